@@ -0,0 +1,28 @@
+//! Feed arbitrary input to the decoders and check that they never panic
+//! and that successfully decoded data round trips.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pricklybirdlib::{
+    convert_from_pricklybird, convert_from_pricklybird_flexible, convert_to_pricklybird,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    for decoded in [
+        convert_from_pricklybird(&input),
+        convert_from_pricklybird_flexible(&input),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        let code = convert_to_pricklybird(&decoded);
+        assert_eq!(
+            decoded,
+            convert_from_pricklybird(&code).unwrap(),
+            "Decoded data did not round trip."
+        );
+    }
+});
@@ -0,0 +1,44 @@
+//! Access to the system clipboard using the `arboard` crate.
+//!
+//! Supported are macOS, Windows and Linux using X11.
+//! On Linux the clipboard content is owned by the process that set it,
+//! so a clipboard manager is required to keep the string available after `prbiconv` exits.
+
+use crate::AppError;
+
+impl From<arboard::Error> for AppError {
+    fn from(error: arboard::Error) -> Self {
+        Self::Clipboard(error.to_string())
+    }
+}
+
+/// Write `text` to the system clipboard.
+pub fn copy(text: &str) -> Result<(), AppError> {
+    arboard::Clipboard::new()?.set_text(text)?;
+    Ok(())
+}
+
+/// Read text from the system clipboard.
+pub fn paste() -> Result<String, AppError> {
+    Ok(arboard::Clipboard::new()?.get_text()?)
+}
+
+#[cfg(test)]
+mod clipboard_tests {
+    use super::*;
+
+    /// Check that clipboard failures are reported as clipboard errors.
+    #[test]
+    fn test_no_clipboard_available() {
+        for error in [
+            arboard::Error::ContentNotAvailable,
+            arboard::Error::ClipboardNotSupported,
+        ] {
+            let message = error.to_string();
+            assert!(
+                matches!(AppError::from(error), AppError::Clipboard(found) if found == message),
+                "Clipboard failure was not reported as a clipboard error."
+            );
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Minimal JSON formatting for the `--json` output mode.
+//!
+//! Only the few value types `prbiconv` emits are supported,
+//! which avoids depending on a serialization library.
+
+use std::fmt::Write as _;
+
+/// Format `value` as a quoted JSON string, escaping characters where required.
+pub fn string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            control if u32::from(control) < 0x20 => {
+                // Writing to a String can not fail.
+                let _ = write!(quoted, "\\u{:04x}", u32::from(control));
+            }
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Format `values` as a JSON array of strings.
+pub fn string_array<'a>(values: impl IntoIterator<Item = &'a str>) -> String {
+    let elements: Vec<String> = values.into_iter().map(string).collect();
+    format!("[{}]", elements.join(","))
+}
+
+/// Format `fields` as a JSON object, the values must already be formatted as JSON.
+pub fn object(fields: &[(&str, String)]) -> String {
+    let members: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{value}", string(key)))
+        .collect();
+    format!("{{{}}}", members.join(","))
+}
+
+#[cfg(test)]
+mod json_tests {
+    use super::*;
+
+    #[test]
+    fn test_string_escaping() {
+        assert_eq!(r#""flea-flux-full""#, string("flea-flux-full"));
+        assert_eq!(r#""a\"b\\c\nd\te\u0001""#, string("a\"b\\c\nd\te\u{1}"));
+    }
+
+    #[test]
+    fn test_object() {
+        let formatted = object(&[
+            ("code", string("flea-flux-full")),
+            ("crc_ok", true.to_string()),
+            ("words", string_array(["flea", "flux"])),
+        ]);
+        assert_eq!(
+            r#"{"code":"flea-flux-full","crc_ok":true,"words":["flea","flux"]}"#,
+            formatted
+        );
+        assert_eq!("[]", string_array([]));
+    }
+}
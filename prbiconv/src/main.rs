@@ -5,28 +5,150 @@
 //! # Usage
 //! We use `xxd` in these examples to convert raw binary to hexadecimal.
 //!
-//! By default conversion from pricklybird to bytes is attempted.
-//! This can be explicitly set using the `-b` flag.
+//! To convert pricklybird to bytes use the `decode` subcommand.
+//! This is also done if no subcommand is given.
 //! ```console
-//! % echo "flea-flux-full" | prbiconv -b | xxd -ps
+//! % echo "flea-flux-full" | prbiconv decode | xxd -ps
 //! 4243
 //! ```
 //!
-//! To convert bytes to pricklybird use the `-p` flag.
+//! To convert bytes to pricklybird use the `encode` subcommand.
 //! ```console
-//! % echo "4243" | xxd -r -p | prbiconv -p
+//! % echo "4243" | xxd -r -p | prbiconv encode
 //! flea-flux-full
 //! ```
+//!
+//! The pricklybird string is written without a trailing newline, so it can be piped
+//! or copied exactly. Use the `-n` flag to append a newline, for example in a terminal.
+//! ```console
+//! % echo "4243" | xxd -r -p | prbiconv encode -n
+//! flea-flux-full
+//! ```
+//!
+//! To use a different separator between the words use the `--separator` flag.
+//! Letters, digits, whitespace other than a space and `:` are not allowed.
+//! ```console
+//! % echo "4243" | xxd -r -p | prbiconv encode --separator .
+//! flea.flux.full
+//! ```
+//!
+//! To use a custom wordlist in place of the standard one use the `--wordlist` flag with
+//! `encode`, `decode` or `verify`. The file must contain 256 lines of unique four letter words,
+//! line `n` contains the word for the byte value `n - 1`.
+//! ```console
+//! % echo "4243" | xxd -r -p | prbiconv encode --wordlist reversed.txt
+//! ruby-room-reef
+//! ```
+//!
+//! To report the size of the input and output and the expansion ratio on stderr
+//! use the `--stats` flag with `encode`.
+//! ```console
+//! % echo "4243" | xxd -r -p | prbiconv encode --stats
+//! input bytes: 2
+//! output characters: 14
+//! words: 3
+//! expansion ratio: 7.00
+//! flea-flux-full
+//! ```
+//!
+//! To check that a pricklybird string is valid without writing the payload use the
+//! `verify` subcommand. The `--count` flag additionally prints its length.
+//! ```console
+//! % echo "flea-flux-full" | prbiconv verify --count
+//! words: 3
+//! bytes: 2
+//! ```
+//!
+//! To convert a pricklybird string to its canonical lowercase form use the `normalize` subcommand.
+//! ```console
+//! % echo " FLEA flux-Full" | prbiconv normalize
+//! flea-flux-full
+//! ```
+//!
+//! To view the decoded bytes as a hex dump use the `--dump` flag.
+//! ```console
+//! % echo "flea-flux-full" | prbiconv decode --dump
+//! 00000000  42 43                                             |BC|
+//! ```
+//!
+//! To reject pricklybird strings that are not in canonical form use the `--strict` flag.
+//! Since this includes surrounding whitespace, the input must not end with a newline.
+//! ```console
+//! % printf "flea-flux-full" | prbiconv decode --strict | xxd -ps
+//! 4243
+//! % echo "FLEA-flux-full" | prbiconv decode --strict
+//! Error: Input is not in canonical form, expected lowercase words separated by single '-' without surrounding whitespace.
+//! ```
+//!
+//! For use in scripts, the `--json` flag writes the result as a JSON object.
+//! A CRC mismatch is reported in the `crc_ok` field when converting to bytes,
+//! other errors are written as an object with an `error` field.
+//! ```console
+//! % echo "flea-flux-full" | prbiconv decode --json
+//! {"payload_hex":"4243","crc_ok":true,"words":["flea","flux","full"]}
+//! % echo "4243" | xxd -r -p | prbiconv encode --json
+//! {"code":"flea-flux-full","words":["flea","flux","full"]}
+//! ```
+//!
+//! To decode a file containing one pricklybird string per line use the `--lines` flag.
+//! Lines that can not be converted are reported with their line number on stderr,
+//! use `--fail-fast` to stop at the first one.
+//! The separator written after each converted line can be set using `--record-separator`.
+//! ```console
+//! % printf "flea-flux-full\nflux-flea-call\n" | prbiconv decode --lines --record-separator "" | xxd -ps
+//! 42434342
+//! ```
+//!
+//! To encode one hexadecimal value per line use the `--lines` and `--hex` flags with `encode`.
+//! Raw binary can not be split into lines, so `--hex` is required.
+//! ```console
+//! % printf "4243\n4342\n" | prbiconv encode --lines --hex
+//! flea-flux-full
+//! flux-flea-call
+//! ```
+//!
+//! When built with the `clipboard` feature, the `--clipboard` flag copies the
+//! pricklybird string to the system clipboard when using `encode`,
+//! and reads the pricklybird string from the clipboard when using `decode` or `verify`.
+//! ```console
+//! % echo "4243" | xxd -r -p | prbiconv encode --clipboard
+//! % prbiconv decode --clipboard | xxd -ps
+//! 4243
+//! ```
+//!
+//! The flags of earlier versions, such as `-p`, `-b`, `--count` and `--normalize`,
+//! are still accepted without a subcommand but hidden from the help output.
+//! They will be removed in a future release.
+//!
+//! # Exit status
+//! Errors are written to stderr as a single line and reported using the exit status:
+//! - `0`: The conversion succeeded
+//! - `1`: Reading or writing data failed
+//! - `2`: The CRC of the pricklybird string is invalid
+//! - `3`: The input could not be parsed, for example because it contains unknown words
+//! - `4`: The command line arguments are invalid
 
-use std::fmt;
+use std::fmt::{self, Write as _};
+use std::fs;
 use std::io::{self, Read, Write};
+use std::process::ExitCode;
 
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 
+use pricklybirdlib::alphabet::{Alphabet, AlphabetError};
 use pricklybirdlib::{
-    DecodeError, PRICKLYBIRD_VERSION, convert_from_pricklybird, convert_to_pricklybird,
+    DecodeError, DecodeOptions, EncodeOptions, HexDump, PRICKLYBIRD_VERSION, StreamError,
+    convert_from_pricklybird_checked, convert_to_pricklybird, decode_stream, decoded_len,
+    encode_stream, encoded_words_len, is_valid_separator, normalize, tokens, word_count,
 };
 
+/// Format output for the `--json` flag.
+mod json;
+
+/// Read from and write to the system clipboard.
+#[cfg(feature = "clipboard")]
+mod clipboard;
+
 /// The conversion failed.
 pub enum AppError {
     /// The conversion failed due to some IO error.
@@ -35,6 +157,56 @@ pub enum AppError {
     Decode(DecodeError),
     /// Incorrect arguments were supplied via the CLI.
     ArgumentError(String),
+    /// Hexadecimal input could not be parsed.
+    Hex(String),
+    /// The input is a valid pricklybird string but not in canonical form, reported with `--strict`.
+    NotCanonical {
+        /// The separator expected between the words.
+        separator: char,
+    },
+    /// The system clipboard could not be accessed.
+    #[cfg(feature = "clipboard")]
+    Clipboard(String),
+    /// A line could not be converted in `--lines` mode with `--fail-fast` set.
+    Line {
+        /// Number of the line that failed, starting at one.
+        line: usize,
+        /// The error encountered while converting the line.
+        error: Box<Self>,
+    },
+    /// Some lines could not be converted in `--lines` mode.
+    LinesFailed(usize),
+}
+
+/// Exit code for errors reading or writing data, including the clipboard.
+const EXIT_IO: u8 = 1;
+/// Exit code for a pricklybird string with an invalid CRC.
+const EXIT_CRC: u8 = 2;
+/// Exit code for input that could not be parsed, such as unknown words or invalid hexadecimal.
+const EXIT_STRUCTURAL: u8 = 3;
+/// Exit code for invalid command line arguments.
+const EXIT_ARGUMENT: u8 = 4;
+
+impl AppError {
+    /// The process exit code reporting this error.
+    ///
+    /// Failures in `--lines` mode are reported as structural errors,
+    /// unless `--fail-fast` is set and the code of the failing line is used.
+    const fn exit_code(&self) -> u8 {
+        match self {
+            Self::Io(_) => EXIT_IO,
+            #[cfg(feature = "clipboard")]
+            Self::Clipboard(_) => EXIT_IO,
+            Self::Decode(DecodeError::InvalidSeparator { .. }) | Self::ArgumentError(_) => {
+                EXIT_ARGUMENT
+            }
+            Self::Decode(error) if error.is_integrity_error() => EXIT_CRC,
+            Self::Decode(_) | Self::Hex(_) | Self::NotCanonical { .. } | Self::LinesFailed(_) => {
+                EXIT_STRUCTURAL
+            }
+            Self::Line { error, .. } => error.exit_code(),
+        }
+    }
 }
 
 impl From<io::Error> for AppError {
@@ -49,6 +221,17 @@ impl From<DecodeError> for AppError {
     }
 }
 
+impl From<StreamError> for AppError {
+    /// Encoding only fails for an invalid separator, which is an argument error.
+    fn from(error: StreamError) -> Self {
+        match error {
+            StreamError::Io(err) => Self::Io(err),
+            StreamError::Decode(err) => Self::Decode(err),
+            StreamError::Encode(err) => Self::ArgumentError(err.to_string()),
+        }
+    }
+}
+
 // Implement Display for AppError to format both error types properly
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -56,6 +239,15 @@ impl fmt::Display for AppError {
             Self::Io(err) => write!(f, "IO error: {err}"),
             Self::Decode(err) => write!(f, "{err}"),
             Self::ArgumentError(msg) => write!(f, "Invalid arguments. {msg}"),
+            Self::Hex(msg) => write!(f, "Invalid hexadecimal input. {msg}"),
+            Self::NotCanonical { separator } => write!(
+                f,
+                "Input is not in canonical form, expected lowercase words separated by single '{separator}' without surrounding whitespace."
+            ),
+            #[cfg(feature = "clipboard")]
+            Self::Clipboard(msg) => write!(f, "Clipboard error: {msg}"),
+            Self::Line { line, error } => write!(f, "Line {line}: {error}"),
+            Self::LinesFailed(count) => write!(f, "{count} lines could not be converted."),
         }
     }
 }
@@ -72,67 +264,963 @@ impl fmt::Debug for AppError {
     name = clap::crate_name!(),
     version = clap::crate_version!(),
     about = format!("{} Implements pricklybird specification {}.",clap::crate_description!(), PRICKLYBIRD_VERSION),
+    args_conflicts_with_subcommands = true,
 )]
 /// Collect arguments supplied via command line.
 struct Cli {
+    /// The conversion to perform, converts from pricklybird to bytes if omitted.
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The flags of earlier versions, used if no subcommand is given.
+    #[command(flatten)]
+    legacy: LegacyArgs,
+}
+
+impl Cli {
+    /// The subcommand to run, translating the legacy flags if no subcommand was given.
+    ///
+    /// # Errors
+    /// Will return `AppError::ArgumentError` if the legacy flags can not be translated.
+    fn into_command(self) -> Result<Command, AppError> {
+        match self.command {
+            Some(command) => Ok(command),
+            None => self.legacy.into_command(),
+        }
+    }
+}
+
+/// Subcommands selecting the conversion to perform.
+#[derive(Subcommand)]
+enum Command {
+    /// Convert bytes to a pricklybird string.
+    Encode(EncodeArgs),
+    /// Convert a pricklybird string to bytes.
+    Decode(DecodeArgs),
+    /// Check that a pricklybird string is valid, including its CRC, without writing the payload.
+    Verify(VerifyArgs),
+    /// Convert a possibly messy pricklybird string to its canonical form.
+    Normalize(NormalizeArgs),
+}
+
+impl Command {
+    /// Whether the `--json` flag is set, so errors are written to the JSON output.
+    const fn json(&self) -> bool {
+        match self {
+            Self::Encode(args) => args.json,
+            Self::Decode(args) => args.json,
+            Self::Verify(_) | Self::Normalize(_) => false,
+        }
+    }
+}
+
+/// Options of the `encode` subcommand.
+// Command line flags are naturally represented as bools.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args)]
+struct EncodeArgs {
+    /// Separator between the words of the pricklybird string, defaults to `-`.
+    #[arg(long = "separator", value_parser = parse_separator, conflicts_with = "json")]
+    separator: Option<char>,
+
+    /// Append a newline to the pricklybird string.
+    /// Off by default, so the output can be piped or copied exactly.
+    #[arg(short = 'n', long = "newline", conflicts_with_all = ["lines", "json"])]
+    newline: bool,
+
+    /// Report the input size, output size, number of words and expansion ratio to stderr.
+    #[arg(long = "stats", conflicts_with_all = ["lines", "json"])]
+    stats: bool,
+
+    /// Write the result as a JSON object, errors are written as a JSON object with an `error` field.
+    #[arg(long = "json", conflicts_with = "lines")]
+    json: bool,
+
+    /// Encode each line of the input separately, requires `--hex`.
+    /// Errors are reported with their line number and the remaining lines are still converted.
+    #[arg(long = "lines", requires = "hex")]
+    lines: bool,
+
+    /// Read each line as hexadecimal in `--lines` mode.
+    #[arg(long = "hex", requires = "lines")]
+    hex: bool,
+
+    /// Separator written after each converted line in `--lines` mode, defaults to a newline.
+    #[arg(long = "record-separator", requires = "lines")]
+    record_separator: Option<String>,
+
+    /// Stop at the first line that can not be converted in `--lines` mode.
+    #[arg(long = "fail-fast", requires = "lines")]
+    fail_fast: bool,
+
+    /// File with 256 lines of unique four letter words, used in place of the standard wordlist.
+    /// Line `n` contains the word for the byte value `n - 1`.
+    #[arg(
+        long = "wordlist",
+        value_name = "PATH",
+        value_parser = parse_wordlist,
+        conflicts_with_all = ["separator", "json"]
+    )]
+    wordlist: Option<Wordlist>,
+
+    /// Write the pricklybird string to the clipboard instead of stdout.
+    #[cfg(feature = "clipboard")]
+    #[arg(long = "clipboard")]
+    clipboard: bool,
+}
+
+/// Options of the `decode` subcommand.
+// Command line flags are naturally represented as bools.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args)]
+struct DecodeArgs {
+    /// Separator between the words of the pricklybird string, defaults to `-`.
+    #[arg(long = "separator", value_parser = parse_separator, conflicts_with = "json")]
+    separator: Option<char>,
+
+    /// Reject pricklybird strings that are not in canonical form,
+    /// including uppercase letters and surrounding whitespace such as a trailing newline.
+    #[arg(long = "strict")]
+    strict: bool,
+
+    /// Print the decoded bytes as a hex dump instead of raw binary.
+    #[arg(long = "dump", conflicts_with_all = ["lines", "json"])]
+    dump: bool,
+
+    /// Write the result as a JSON object, errors are written as a JSON object with an `error` field.
+    #[arg(long = "json", conflicts_with = "lines")]
+    json: bool,
+
+    /// Decode each line of the input separately.
+    /// Errors are reported with their line number and the remaining lines are still converted.
+    #[arg(long = "lines")]
+    lines: bool,
+
+    /// Separator written after each converted line in `--lines` mode, defaults to a newline.
+    #[arg(long = "record-separator", requires = "lines")]
+    record_separator: Option<String>,
+
+    /// Stop at the first line that can not be converted in `--lines` mode.
+    #[arg(long = "fail-fast", requires = "lines")]
+    fail_fast: bool,
+
+    /// File with 256 lines of unique four letter words, used in place of the standard wordlist.
+    /// Line `n` contains the word for the byte value `n - 1`.
+    #[arg(
+        long = "wordlist",
+        value_name = "PATH",
+        value_parser = parse_wordlist,
+        conflicts_with_all = ["separator", "json"]
+    )]
+    wordlist: Option<Wordlist>,
+
+    /// Read the pricklybird string from the clipboard instead of stdin.
+    #[cfg(feature = "clipboard")]
+    #[arg(long = "clipboard")]
+    clipboard: bool,
+}
+
+/// Options of the `verify` subcommand.
+#[derive(Args)]
+struct VerifyArgs {
+    /// Separator between the words of the pricklybird string, defaults to `-`.
+    #[arg(long = "separator", value_parser = parse_separator, conflicts_with = "count")]
+    separator: Option<char>,
+
+    /// Reject pricklybird strings that are not in canonical form,
+    /// including uppercase letters and surrounding whitespace such as a trailing newline.
+    #[arg(long = "strict")]
+    strict: bool,
+
+    /// Print the number of words and decoded bytes of the pricklybird string.
+    #[arg(long = "count")]
+    count: bool,
+
+    /// File with 256 lines of unique four letter words, used in place of the standard wordlist.
+    /// Line `n` contains the word for the byte value `n - 1`.
+    #[arg(
+        long = "wordlist",
+        value_name = "PATH",
+        value_parser = parse_wordlist,
+        conflicts_with = "separator"
+    )]
+    wordlist: Option<Wordlist>,
+
+    /// Read the pricklybird string from the clipboard instead of stdin.
+    #[cfg(feature = "clipboard")]
+    #[arg(long = "clipboard")]
+    clipboard: bool,
+}
+
+/// Options of the `normalize` subcommand.
+#[derive(Args)]
+struct NormalizeArgs {
+    /// Append a newline to the pricklybird string.
+    /// Off by default, so the output can be piped or copied exactly.
+    #[arg(short = 'n', long = "newline")]
+    newline: bool,
+}
+
+/// A wordlist loaded from the file given to `--wordlist` by `parse_wordlist`.
+#[derive(Clone)]
+struct Wordlist {
+    /// The words indexed by byte value.
+    words: Box<[String; 256]>,
+}
+
+impl Wordlist {
+    /// The words indexed by byte value, as expected by `Alphabet::new`.
+    fn words(&self) -> [&str; 256] {
+        self.words.each_ref().map(String::as_str)
+    }
+}
+
+/// The flags of earlier versions without subcommands.
+///
+/// They are hidden from the help output but still accepted,
+/// `LegacyArgs::into_command` translates them into the equivalent subcommand.
+// Command line flags are naturally represented as bools.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args)]
+struct LegacyArgs {
     /// Attempt conversion from pricklybird string to bytes.
-    #[arg(short = 'b', long = "convert-from-pricklybird")]
+    #[arg(short = 'b', long = "convert-from-pricklybird", hide = true)]
     convert_from: bool,
 
     /// Convert bytes to pricklybird string.
-    #[arg(short = 'p', long = "convert-to-pricklybird")]
+    #[arg(short = 'p', long = "convert-to-pricklybird", hide = true)]
     convert_to: bool,
+
+    /// Write the pricklybird string to the clipboard when converting to pricklybird,
+    /// read it from the clipboard when converting to bytes.
+    #[cfg(feature = "clipboard")]
+    #[arg(long = "clipboard", hide = true)]
+    clipboard: bool,
+
+    /// Print the number of words and decoded bytes of a pricklybird string instead of converting it.
+    #[arg(long = "count", hide = true)]
+    count: bool,
+
+    /// Convert a possibly messy pricklybird string to its canonical form.
+    #[arg(long = "normalize", hide = true, conflicts_with = "count")]
+    normalize: bool,
+
+    /// Print the decoded bytes as a hex dump instead of raw binary.
+    #[arg(long = "dump", hide = true, conflicts_with_all = ["convert_to", "count", "normalize"])]
+    dump: bool,
+
+    /// Convert each line of the input separately.
+    /// Errors are reported with their line number and the remaining lines are still converted.
+    /// Requires `--hex` when converting to pricklybird.
+    #[arg(long = "lines", hide = true, conflicts_with_all = ["count", "normalize", "dump"])]
+    lines: bool,
+
+    /// Read each line as hexadecimal when converting to pricklybird in `--lines` mode.
+    #[arg(
+        long = "hex",
+        hide = true,
+        requires = "lines",
+        conflicts_with = "convert_from"
+    )]
+    hex: bool,
+
+    /// Separator written after each converted line in `--lines` mode, defaults to a newline.
+    #[arg(long = "record-separator", hide = true, requires = "lines")]
+    record_separator: Option<String>,
+
+    /// Stop at the first line that can not be converted in `--lines` mode.
+    #[arg(long = "fail-fast", hide = true, requires = "lines")]
+    fail_fast: bool,
+
+    /// Reject pricklybird strings that are not in canonical form when converting to bytes,
+    /// including uppercase letters and surrounding whitespace such as a trailing newline.
+    #[arg(long = "strict", hide = true, conflicts_with_all = ["convert_to", "count", "normalize"])]
+    strict: bool,
+
+    /// Separator between the words of pricklybird strings, defaults to `-`.
+    #[arg(
+        long = "separator",
+        hide = true,
+        value_parser = parse_separator,
+        conflicts_with_all = ["count", "normalize", "json"]
+    )]
+    separator: Option<char>,
+
+    /// Report the input size, output size, number of words and expansion ratio to stderr
+    /// when converting to pricklybird.
+    #[arg(long = "stats", hide = true, requires = "convert_to", conflicts_with_all = ["lines", "json"])]
+    stats: bool,
+
+    /// Append a newline to the pricklybird string written when converting to pricklybird
+    /// or normalizing. Off by default, so the output can be piped or copied exactly.
+    #[arg(
+        short = 'n',
+        long = "newline",
+        hide = true,
+        conflicts_with_all = ["convert_from", "count", "dump", "lines", "json"]
+    )]
+    newline: bool,
+
+    /// Write the result as a JSON object, errors are written as a JSON object with an `error` field.
+    #[arg(long = "json", hide = true, conflicts_with_all = ["count", "normalize", "dump", "lines"])]
+    json: bool,
 }
 
-/// Read from `input` and write to `output`.
-/// Attemps conversion from pricklybird string to bytes by default.
-/// Setting the `-p` flag will instead convert bytes to a pricklybird string.
-fn convert(cli: &Cli, mut input: impl Read, mut output: impl Write) -> Result<(), AppError> {
-    if cli.convert_to && cli.convert_from {
-        return Err(AppError::ArgumentError(
-            "Can not convert from and to pricklybird at the same time.".to_owned(),
-        ));
+impl LegacyArgs {
+    /// Translate the flags into the equivalent subcommand.
+    ///
+    /// `--normalize` selects `normalize`, `--count` selects `verify --count`
+    /// and `-p` selects `encode`, otherwise `decode` is used.
+    ///
+    /// # Errors
+    /// Will return `AppError::ArgumentError` if both `-p` and `-b` are set,
+    /// or if `--lines` is used with `-p` but without `--hex`.
+    fn into_command(self) -> Result<Command, AppError> {
+        if self.convert_to && self.convert_from {
+            return Err(AppError::ArgumentError(
+                "Can not convert from and to pricklybird at the same time.".to_owned(),
+            ));
+        }
+        if self.normalize {
+            return Ok(Command::Normalize(NormalizeArgs {
+                newline: self.newline,
+            }));
+        }
+        if self.count {
+            return Ok(Command::Verify(VerifyArgs {
+                separator: self.separator,
+                strict: self.strict,
+                count: true,
+                wordlist: None,
+                #[cfg(feature = "clipboard")]
+                clipboard: self.clipboard,
+            }));
+        }
+        if self.convert_to {
+            if self.lines && !self.hex {
+                return Err(AppError::ArgumentError(
+                    "Converting lines to pricklybird requires the `--hex` flag.".to_owned(),
+                ));
+            }
+            return Ok(Command::Encode(EncodeArgs {
+                separator: self.separator,
+                newline: self.newline,
+                stats: self.stats,
+                json: self.json,
+                lines: self.lines,
+                hex: self.hex,
+                record_separator: self.record_separator,
+                fail_fast: self.fail_fast,
+                wordlist: None,
+                #[cfg(feature = "clipboard")]
+                clipboard: self.clipboard,
+            }));
+        }
+        Ok(Command::Decode(DecodeArgs {
+            separator: self.separator,
+            strict: self.strict,
+            dump: self.dump,
+            json: self.json,
+            lines: self.lines,
+            record_separator: self.record_separator,
+            fail_fast: self.fail_fast,
+            wordlist: None,
+            #[cfg(feature = "clipboard")]
+            clipboard: self.clipboard,
+        }))
     }
-    if cli.convert_to {
+}
+
+/// Read from `input` and write to `output`, performing the conversion selected by `command`.
+///
+/// Statistics and the errors of failed lines in `--lines` mode are reported to `errors`.
+fn convert(
+    command: &Command,
+    input: impl Read,
+    output: impl Write,
+    errors: impl Write,
+) -> Result<(), AppError> {
+    match command {
+        Command::Encode(args) => with_alphabet(args.wordlist.as_ref(), |alphabet| {
+            convert_encode(args, alphabet, input, output, errors)
+        }),
+        Command::Decode(args) => with_alphabet(args.wordlist.as_ref(), |alphabet| {
+            convert_decode(args, alphabet, input, output, errors)
+        }),
+        Command::Verify(args) => with_alphabet(args.wordlist.as_ref(), |alphabet| {
+            convert_verify(args, alphabet, input, output)
+        }),
+        Command::Normalize(args) => convert_normalize(args, input, output),
+    }
+}
+
+/// Convert the bytes read from `input` to a pricklybird string written to `output` using `encode`.
+///
+/// Setting the `--newline` flag will append a newline to the pricklybird string.
+/// Setting the `--stats` flag will report encoding statistics to `errors` using `write_stats`.
+/// Setting the `--lines` flag will convert each line separately using `encode_lines`.
+/// Setting the `--json` flag will output a JSON object using `write_json`.
+fn convert_encode(
+    args: &EncodeArgs,
+    alphabet: Option<&Alphabet<'_>>,
+    mut input: impl Read,
+    mut output: impl Write,
+    errors: impl Write,
+) -> Result<(), AppError> {
+    if args.lines {
+        return encode_lines(args, alphabet, input, output, errors);
+    }
+    if args.json {
+        // Errors reading the input are reported in the JSON object as well.
+        let mut buffer = Vec::<u8>::new();
+        let result = input.read_to_end(&mut buffer).map(|_| encode_json(&buffer));
+        return write_json(output, result.map_err(AppError::from));
+    }
+    // The statistics need the length of the input, so it is read completely first.
+    let input_len = if args.stats {
         let mut buffer = Vec::<u8>::new();
         let _ = input.read_to_end(&mut buffer)?;
-        let output_words = convert_to_pricklybird(&buffer);
-        write!(output, "{}", &output_words)?;
-        output.flush()?;
+        encode(alphabet, args.separator, buffer.as_slice(), &mut output)?;
+        Some(buffer.len())
     } else {
-        let mut buffer = String::new();
-        let _ = input.read_to_string(&mut buffer)?;
-        let output_bytes = convert_from_pricklybird(&buffer)?;
-        output.write_all(&output_bytes)?;
+        encode(alphabet, args.separator, input, &mut output)?;
+        None
+    };
+    if args.newline {
+        writeln!(output)?;
         output.flush()?;
     }
+    if let Some(len) = input_len {
+        write_stats(errors, len)?;
+    }
+    Ok(())
+}
+
+/// Convert the pricklybird string read from `input` to bytes written to `output` using `decode`.
+///
+/// Setting the `--dump` flag will output the decoded bytes as a hex dump.
+/// Setting the `--lines` flag will convert each line separately using `decode_lines`.
+/// Setting the `--json` flag will output a JSON object using `write_json`.
+fn convert_decode(
+    args: &DecodeArgs,
+    alphabet: Option<&Alphabet<'_>>,
+    mut input: impl Read,
+    mut output: impl Write,
+    errors: impl Write,
+) -> Result<(), AppError> {
+    if args.lines {
+        return decode_lines(args, alphabet, input, output, errors);
+    }
+    if args.json {
+        // Errors reading the input are reported in the JSON object as well.
+        let mut buffer = Vec::<u8>::new();
+        let result = input
+            .read_to_end(&mut buffer)
+            .map_err(AppError::from)
+            .and_then(|_| decode_json(args.strict, &buffer));
+        return write_json(output, result);
+    }
+    if args.dump {
+        let mut output_bytes = Vec::<u8>::new();
+        decode(
+            alphabet,
+            args.separator,
+            args.strict,
+            input,
+            &mut output_bytes,
+        )?;
+        write!(output, "{}", HexDump(&output_bytes))?;
+        output.flush()?;
+    } else {
+        decode(alphabet, args.separator, args.strict, input, output)?;
+    }
+    Ok(())
+}
+
+/// Check that the pricklybird string read from `input` is valid without writing the payload.
+///
+/// Setting the `--count` flag will write the number of words and decoded bytes to `output`.
+fn convert_verify(
+    args: &VerifyArgs,
+    alphabet: Option<&Alphabet<'_>>,
+    mut input: impl Read,
+    mut output: impl Write,
+) -> Result<(), AppError> {
+    let mut buffer = String::new();
+    let _ = input.read_to_string(&mut buffer)?;
+    // Decode to make sure malformed input is reported.
+    decode(
+        alphabet,
+        args.separator,
+        args.strict,
+        buffer.as_bytes(),
+        io::sink(),
+    )?;
+    if args.count {
+        writeln!(output, "words: {}", word_count(&buffer))?;
+        writeln!(output, "bytes: {}", decoded_len(&buffer))?;
+    }
+    output.flush()?;
     Ok(())
 }
 
+/// Write the canonical form of the pricklybird string read from `input` to `output`.
+///
+/// Setting the `--newline` flag will append a newline to the pricklybird string.
+fn convert_normalize(
+    args: &NormalizeArgs,
+    mut input: impl Read,
+    mut output: impl Write,
+) -> Result<(), AppError> {
+    let mut buffer = String::new();
+    let _ = input.read_to_string(&mut buffer)?;
+    write!(output, "{}", normalize(&buffer)?)?;
+    if args.newline {
+        writeln!(output)?;
+    }
+    output.flush()?;
+    Ok(())
+}
+
+/// Write the statistics reported by `--stats` for encoding `input_len` bytes.
+///
+/// Every word has four characters and is followed by a single character separator,
+/// except for the last one, regardless of the wordlist and separator used.
+/// The expansion ratio is the number of output characters per input byte.
+fn write_stats(mut stats: impl Write, input_len: usize) -> Result<(), AppError> {
+    let words = encoded_words_len(input_len);
+    let output_chars = (words * 5).saturating_sub(1);
+    writeln!(stats, "input bytes: {input_len}")?;
+    writeln!(stats, "output characters: {output_chars}")?;
+    writeln!(stats, "words: {words}")?;
+    // Integer arithmetic with two decimal places avoids lossy float conversion.
+    match (output_chars * 100).checked_div(input_len) {
+        Some(ratio_hundredths) => writeln!(
+            stats,
+            "expansion ratio: {}.{:02}",
+            ratio_hundredths / 100,
+            ratio_hundredths % 100
+        )?,
+        None => writeln!(stats, "expansion ratio: n/a")?,
+    }
+    stats.flush()?;
+    Ok(())
+}
+
+/// Parse the value of `--separator`, which must be a single character accepted by `is_valid_separator`.
+fn parse_separator(value: &str) -> Result<char, String> {
+    let mut characters = value.chars();
+    let (Some(separator), None) = (characters.next(), characters.next()) else {
+        return Err("The separator must be a single character.".to_owned());
+    };
+    if !is_valid_separator(separator) {
+        return Err(DecodeError::InvalidSeparator { separator }.to_string());
+    }
+    Ok(separator)
+}
+
+/// Load the file given to `--wordlist`.
+///
+/// The file must contain exactly 256 lines, surrounding whitespace of each line is ignored.
+/// The words are checked using `wordlist_alphabet`, so invalid wordlists are rejected
+/// while parsing the arguments.
+fn parse_wordlist(path: &str) -> Result<Wordlist, String> {
+    let text = fs::read_to_string(path).map_err(|err| format!("Unable to read the file. {err}"))?;
+    let lines: Vec<String> = text.lines().map(|line| line.trim().to_owned()).collect();
+    let words: Box<[String; 256]> =
+        lines
+            .into_boxed_slice()
+            .try_into()
+            .map_err(|found: Box<[String]>| {
+                format!(
+                    "The wordlist must contain exactly 256 lines, found {}.",
+                    found.len()
+                )
+            })?;
+    let wordlist = Wordlist { words };
+    let _ = wordlist_alphabet(&wordlist.words())?;
+    Ok(wordlist)
+}
+
+/// Build an alphabet from the words of a wordlist file, indexed by byte value.
+///
+/// Invalid words are reported with the number of the line they appear on.
+fn wordlist_alphabet<'a>(words: &'a [&'a str; 256]) -> Result<Alphabet<'a>, String> {
+    Alphabet::new(words).map_err(|err| match err {
+        AlphabetError::InvalidWord { index } => format!(
+            "Line {}: The word must consist of four lowercase ASCII letters.",
+            index + 1
+        ),
+        AlphabetError::DuplicateWord { index } => {
+            format!("Line {}: The word appears more than once.", index + 1)
+        }
+        other => other.to_string(),
+    })
+}
+
+/// Build the alphabet of `wordlist` and pass it to `convert`, or `None` if no wordlist is set.
+///
+/// The alphabet borrows from the words, so it only lives for the duration of the conversion.
+fn with_alphabet(
+    wordlist: Option<&Wordlist>,
+    convert: impl FnOnce(Option<&Alphabet<'_>>) -> Result<(), AppError>,
+) -> Result<(), AppError> {
+    let Some(words) = wordlist.map(Wordlist::words) else {
+        return convert(None);
+    };
+    let alphabet = wordlist_alphabet(&words).map_err(AppError::ArgumentError)?;
+    convert(Some(&alphabet))
+}
+
+/// Convert the bytes read from `input` to a pricklybird string written to `output`.
+///
+/// Uses the stream function of the wordlist set by `--wordlist` or of the separator
+/// set by `--separator`, and `encode_stream` if neither is set.
+fn encode(
+    wordlist: Option<&Alphabet<'_>>,
+    separator: Option<char>,
+    input: impl Read,
+    output: impl Write,
+) -> Result<(), AppError> {
+    match (wordlist, separator) {
+        (Some(alphabet), _) => alphabet.encode_stream(input, output)?,
+        (None, Some(custom_separator)) => EncodeOptions::new()
+            .separator(custom_separator)
+            .encode_stream(input, output)?,
+        (None, None) => encode_stream(input, output)?,
+    }
+    Ok(())
+}
+
+/// Convert the pricklybird string read from `input` to bytes written to `output`.
+///
+/// Uses the stream function of the wordlist set by `--wordlist` or of the separator
+/// set by `--separator`, and `decode_stream` if neither is set.
+/// If `strict` is set, input that is not in canonical form is rejected before decoding.
+fn decode(
+    wordlist: Option<&Alphabet<'_>>,
+    separator: Option<char>,
+    strict: bool,
+    mut input: impl Read,
+    output: impl Write,
+) -> Result<(), AppError> {
+    let mut words = Vec::<u8>::new();
+    let _ = input.read_to_end(&mut words)?;
+    if strict {
+        let words_separator = separator.unwrap_or('-');
+        if !std::str::from_utf8(&words).is_ok_and(|text| is_canonical(text, words_separator)) {
+            return Err(AppError::NotCanonical {
+                separator: words_separator,
+            });
+        }
+    }
+    let words_input = words.as_slice();
+    match (wordlist, separator) {
+        (Some(alphabet), _) => alphabet.decode_stream(words_input, output)?,
+        (None, Some(custom_separator)) => DecodeOptions::new()
+            .separator(custom_separator)
+            .decode_stream(words_input, output)?,
+        (None, None) => decode_stream(words_input, output)?,
+    }
+    Ok(())
+}
+
+/// Whether `words` is in canonical form, as rejected by `--strict` otherwise.
+///
+/// The input is canonical if it matches its lowercased words joined by `separator`,
+/// so surrounding whitespace, uppercase letters and other separators are not.
+fn is_canonical(words: &str, separator: char) -> bool {
+    let canonical_words: Vec<String> = words
+        .trim()
+        .split(separator)
+        .map(str::to_ascii_lowercase)
+        .collect();
+    canonical_words.join(separator.encode_utf8(&mut [0; 4])) == words
+}
+
+/// Encode each line of `input`, read as hexadecimal, and write the results to `output`.
+///
+/// Raw binary can not be split into lines since it may contain any byte,
+/// so this requires the `--hex` flag. See `convert_lines` for the handling of failed lines.
+fn encode_lines<W: Write>(
+    args: &EncodeArgs,
+    alphabet: Option<&Alphabet<'_>>,
+    input: impl Read,
+    output: W,
+    errors: impl Write,
+) -> Result<(), AppError> {
+    let encode_line = |line: &str, line_output: &mut W| {
+        let data = parse_hex(line)?;
+        encode(alphabet, args.separator, data.as_slice(), line_output)
+    };
+    let record_separator = args.record_separator.as_deref();
+    convert_lines(
+        record_separator,
+        args.fail_fast,
+        input,
+        output,
+        errors,
+        encode_line,
+    )
+}
+
+/// Decode each line of `input` as a separate pricklybird string and write the results to `output`.
+///
+/// See `convert_lines` for the handling of failed lines.
+fn decode_lines<W: Write>(
+    args: &DecodeArgs,
+    alphabet: Option<&Alphabet<'_>>,
+    input: impl Read,
+    output: W,
+    errors: impl Write,
+) -> Result<(), AppError> {
+    let decode_line = |line: &str, line_output: &mut W| {
+        decode(
+            alphabet,
+            args.separator,
+            args.strict,
+            line.as_bytes(),
+            line_output,
+        )
+    };
+    let record_separator = args.record_separator.as_deref();
+    convert_lines(
+        record_separator,
+        args.fail_fast,
+        input,
+        output,
+        errors,
+        decode_line,
+    )
+}
+
+/// Convert each line of `input` separately using `convert_line`, which writes the result to `output`.
+///
+/// `convert_line` must not write anything if the line fails to convert,
+/// like the stream functions used by `encode` and `decode`.
+/// Each converted line is followed by `record_separator`, a newline if it is not set,
+/// and blank lines are skipped.
+/// Lines that fail to convert are reported to `errors` with their line number,
+/// unless `fail_fast` is set, in which case the first failure is returned.
+fn convert_lines<W: Write>(
+    record_separator: Option<&str>,
+    fail_fast: bool,
+    mut input: impl Read,
+    mut output: W,
+    mut errors: impl Write,
+    convert_line: impl Fn(&str, &mut W) -> Result<(), AppError>,
+) -> Result<(), AppError> {
+    let mut buffer = String::new();
+    let _ = input.read_to_string(&mut buffer)?;
+
+    let mut failed_lines = 0;
+    for (index, line) in buffer.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match convert_line(line, &mut output) {
+            Ok(()) => {
+                output.write_all(record_separator.unwrap_or("\n").as_bytes())?;
+            }
+            Err(error) if fail_fast => {
+                output.flush()?;
+                return Err(AppError::Line {
+                    line: index + 1,
+                    error: Box::new(error),
+                });
+            }
+            Err(error) => {
+                writeln!(errors, "Line {}: {error}", index + 1)?;
+                failed_lines += 1;
+            }
+        }
+    }
+    output.flush()?;
+    if failed_lines > 0 {
+        return Err(AppError::LinesFailed(failed_lines));
+    }
+    Ok(())
+}
+
+/// Write the JSON object `result` to `output`.
+///
+/// If the conversion failed, an object with the error message in the `error` field is written
+/// and the error is returned.
+fn write_json(mut output: impl Write, result: Result<String, AppError>) -> Result<(), AppError> {
+    let formatted = match &result {
+        Ok(formatted) => formatted.clone(),
+        Err(error) => json::object(&[("error", json::string(&error.to_string()))]),
+    };
+    writeln!(output, "{formatted}")?;
+    output.flush()?;
+    result.map(|_| ())
+}
+
+/// Encode bytes and format the code and its words as a JSON object.
+fn encode_json(data: &[u8]) -> String {
+    let code = convert_to_pricklybird(data);
+    json::object(&[
+        ("code", json::string(&code)),
+        (
+            "words",
+            json::string_array(code.split('-').filter(|word| !word.is_empty())),
+        ),
+    ])
+}
+
+/// Decode a pricklybird string and format the result as a JSON object.
+///
+/// The payload is written as hexadecimal, together with whether the CRC matched and the words.
+/// A CRC mismatch is reported in the `crc_ok` field instead of as an error.
+/// Input that is not valid UTF-8 is reported as a `DecodeError::General`.
+fn decode_json(strict: bool, input: &[u8]) -> Result<String, AppError> {
+    let words = std::str::from_utf8(input)
+        .map_err(|_| DecodeError::General("Input is not valid UTF-8.".into()))?;
+    if strict && !is_canonical(words, '-') {
+        return Err(AppError::NotCanonical { separator: '-' });
+    }
+    let result = convert_from_pricklybird_checked(words)?;
+    let input_words: Vec<String> = tokens(words).map(str::to_ascii_lowercase).collect();
+    Ok(json::object(&[
+        ("payload_hex", json::string(&format_hex(&result.payload))),
+        ("crc_ok", result.crc_ok.to_string()),
+        (
+            "words",
+            json::string_array(input_words.iter().map(String::as_str)),
+        ),
+    ]))
+}
+
+/// Format bytes as lowercase hexadecimal digits.
+fn format_hex(data: &[u8]) -> String {
+    data.iter()
+        .fold(String::with_capacity(data.len() * 2), |mut hex, byte| {
+            // Writing to a String can not fail.
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// Parse a line of hexadecimal digits into bytes, ignoring surrounding whitespace.
+fn parse_hex(line: &str) -> Result<Vec<u8>, AppError> {
+    let digits = line.trim().as_bytes();
+    if !digits.len().is_multiple_of(2) {
+        return Err(AppError::Hex(
+            "The number of digits must be even.".to_owned(),
+        ));
+    }
+    // `from_str_radix` accepts a leading `+`, so every character is checked first.
+    if !digits.iter().all(u8::is_ascii_hexdigit) {
+        return Err(AppError::Hex(
+            "Only the digits 0-9 and a-f are allowed.".to_owned(),
+        ));
+    }
+    digits
+        .chunks_exact(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair_str| u8::from_str_radix(pair_str, 16).ok())
+                .ok_or_else(|| AppError::Hex("Only the digits 0-9 and a-f are allowed.".to_owned()))
+        })
+        .collect()
+}
+
+/// Parse the arguments and run the conversion.
+///
+/// Errors are written to stderr as a single line and reported using the exit code
+/// returned by `AppError::exit_code`.
+/// Invalid arguments rejected by clap also exit with `EXIT_ARGUMENT`,
+/// printing help or the version exits successfully.
+fn main() -> ExitCode {
+    let (result, json) = match Cli::try_parse().map(Cli::into_command) {
+        Ok(Ok(command)) => (run(&command), command.json()),
+        Ok(Err(error)) => (Err(error), false),
+        Err(error) => {
+            let _ = error.print();
+            return if error.use_stderr() {
+                ExitCode::from(EXIT_ARGUMENT)
+            } else {
+                ExitCode::SUCCESS
+            };
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            // In JSON mode the error was already written to the output.
+            if !json {
+                let _ = writeln!(io::stderr(), "Error: {error}");
+            }
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
 /// Read from stdin and output to stdout.
 /// Pass the streams to the `convert` function.
-pub fn main() -> Result<(), AppError> {
-    let cli = Cli::parse();
-    convert(&cli, io::stdin(), io::stdout())?;
-    Ok(())
+///
+/// When `--clipboard` is set, the clipboard is used in place of stdout when encoding,
+/// and in place of stdin when decoding or verifying.
+///
+/// # Errors
+/// Will return `AppError` if reading, writing or the conversion fails.
+fn run(command: &Command) -> Result<(), AppError> {
+    #[cfg(feature = "clipboard")]
+    match command {
+        Command::Encode(EncodeArgs {
+            clipboard: true, ..
+        }) => {
+            let mut output = Vec::<u8>::new();
+            convert(command, io::stdin(), &mut output, io::stderr())?;
+            // The pricklybird string is always ASCII.
+            return clipboard::copy(&String::from_utf8_lossy(&output));
+        }
+        Command::Decode(DecodeArgs {
+            clipboard: true, ..
+        })
+        | Command::Verify(VerifyArgs {
+            clipboard: true, ..
+        }) => {
+            let input = clipboard::paste()?;
+            return convert(command, input.as_bytes(), io::stdout(), io::stderr());
+        }
+        _ => {}
+    }
+    convert(command, io::stdin(), io::stdout(), io::stderr())
 }
 
 #[cfg(test)]
 mod prbiconv_tests {
     use super::*;
+    use clap::CommandFactory as _;
     use std::io::Cursor;
 
+    /// Parse the command line `args` into the command to run, like `main` does.
+    fn parse(args: &[&str]) -> Command {
+        Cli::try_parse_from(args.iter().copied())
+            .unwrap()
+            .into_command()
+            .unwrap()
+    }
+
+    /// Input that fails with an IO error on every read.
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("read failed"))
+        }
+    }
+
     #[test]
     fn test_convert_to_pricklybird() {
-        let cli = Cli {
-            convert_from: false,
-            convert_to: true,
-        };
+        let command = parse(&["prbiconv", "encode"]);
 
-        let input = Cursor::new([0x42u8, 0x43]);
+        let input = Cursor::new([0x42_u8, 0x43]);
         let mut output = Cursor::new(Vec::new());
 
-        convert(&cli, input, &mut output).unwrap();
+        convert(&command, input, &mut output, io::sink()).unwrap();
 
         let output_words = String::from_utf8(output.into_inner()).unwrap();
         assert_eq!(
@@ -143,40 +1231,776 @@ mod prbiconv_tests {
 
     #[test]
     fn test_convert_from_pricklybird() {
-        let cli = Cli {
-            convert_from: true,
-            convert_to: false,
-        };
+        let command = parse(&["prbiconv", "decode"]);
 
         let input = Cursor::new("flea-flux-full");
         let mut output = Cursor::new(Vec::new());
 
-        convert(&cli, input, &mut output).unwrap();
+        convert(&command, input, &mut output, io::sink()).unwrap();
 
         let result_bytes = output.into_inner();
         assert_eq!(
-            vec![0x42u8, 0x43],
+            vec![0x42_u8, 0x43],
             result_bytes,
             "prbiconv incorrectly converted 'flea-flux-full' to bytes."
         );
     }
 
     #[test]
-    fn test_convert_both_flags_error() {
-        let cli = Cli {
-            convert_from: true,
-            convert_to: true,
+    fn test_convert_from_pricklybird_error() {
+        let command = parse(&["prbiconv"]);
+        let mut output = Cursor::new(Vec::new());
+        assert!(
+            matches!(
+                convert(
+                    &command,
+                    Cursor::new("flux-flea-full"),
+                    &mut output,
+                    io::sink()
+                ),
+                Err(AppError::Decode(DecodeError::CRCError))
+            ),
+            "prbiconv did not report the decoding error of the stream."
+        );
+    }
+
+    #[test]
+    fn test_newline() {
+        let command = parse(&["prbiconv", "encode", "--newline"]);
+        let mut output = Cursor::new(Vec::new());
+        convert(
+            &command,
+            Cursor::new([0x42_u8, 0x43]),
+            &mut output,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(b"flea-flux-full\n".to_vec(), output.into_inner());
+
+        let normalize_command = parse(&["prbiconv", "normalize", "--newline"]);
+        let mut normalized = Cursor::new(Vec::new());
+        convert(
+            &normalize_command,
+            Cursor::new(" FLEA-flux-full\n"),
+            &mut normalized,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(b"flea-flux-full\n".to_vec(), normalized.into_inner());
+    }
+
+    #[test]
+    fn test_separator() {
+        let command = parse(&["prbiconv", "encode", "--separator", "."]);
+        let mut output = Cursor::new(Vec::new());
+        convert(
+            &command,
+            Cursor::new([0x42_u8, 0x43]),
+            &mut output,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(b"flea.flux.full".to_vec(), output.into_inner());
+
+        let decode_command = parse(&["prbiconv", "decode", "--separator", "."]);
+        let mut decoded = Cursor::new(Vec::new());
+        convert(
+            &decode_command,
+            Cursor::new("flea.flux.full\n"),
+            &mut decoded,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(vec![0x42_u8, 0x43], decoded.into_inner());
+
+        for invalid in ["x", ":", "\t", "--", ""] {
+            assert!(
+                Cli::try_parse_from(["prbiconv", "decode", "--separator", invalid]).is_err(),
+                "Separator '{}' was accepted.",
+                invalid.escape_default()
+            );
+        }
+    }
+
+    #[test]
+    fn test_wordlist() {
+        let mut reversed_wordlist = pricklybirdlib::constants::WORDLIST;
+        reversed_wordlist.reverse();
+        let wordlist_path =
+            std::env::temp_dir().join(format!("prbiconv-{}.txt", std::process::id()));
+        fs::write(&wordlist_path, reversed_wordlist.join("\n")).unwrap();
+        let path = wordlist_path.to_str().unwrap();
+
+        let command = parse(&["prbiconv", "encode", "--wordlist", path]);
+        let mut output = Cursor::new(Vec::new());
+        convert(
+            &command,
+            Cursor::new([0x42_u8, 0x43]),
+            &mut output,
+            io::sink(),
+        )
+        .unwrap();
+        let code = String::from_utf8(output.into_inner()).unwrap();
+        assert_eq!(
+            Alphabet::new(&reversed_wordlist)
+                .unwrap()
+                .encode(&[0x42, 0x43]),
+            code
+        );
+
+        let decode_command = parse(&["prbiconv", "decode", "--wordlist", path]);
+        let mut decoded = Cursor::new(Vec::new());
+        convert(&decode_command, Cursor::new(code), &mut decoded, io::sink()).unwrap();
+        assert_eq!(vec![0x42_u8, 0x43], decoded.into_inner());
+
+        fs::write(&wordlist_path, reversed_wordlist[..255].join("\n")).unwrap();
+        assert_eq!(
+            Some("The wordlist must contain exactly 256 lines, found 255.".to_owned()),
+            parse_wordlist(path).err()
+        );
+        reversed_wordlist[255] = reversed_wordlist[0];
+        fs::write(&wordlist_path, reversed_wordlist.join("\n")).unwrap();
+        assert_eq!(
+            Some("Line 256: The word appears more than once.".to_owned()),
+            parse_wordlist(path).err()
+        );
+        reversed_wordlist[9] = "Four";
+        fs::write(&wordlist_path, reversed_wordlist.join("\n")).unwrap();
+        assert_eq!(
+            Some("Line 10: The word must consist of four lowercase ASCII letters.".to_owned()),
+            parse_wordlist(path).err()
+        );
+        assert!(
+            Cli::try_parse_from(["prbiconv", "-p", "--wordlist", path]).is_err(),
+            "The wordlist was accepted without a subcommand."
+        );
+        fs::remove_file(&wordlist_path).unwrap();
+        assert!(
+            parse_wordlist(path).is_err(),
+            "Missing wordlist file was accepted."
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = Cursor::new(Vec::new());
+        write_stats(&mut stats, 2).unwrap();
+        assert_eq!(
+            "input bytes: 2\noutput characters: 14\nwords: 3\nexpansion ratio: 7.00\n",
+            String::from_utf8(stats.into_inner()).unwrap()
+        );
+
+        let mut long_stats = Cursor::new(Vec::new());
+        write_stats(&mut long_stats, 3).unwrap();
+        assert!(
+            String::from_utf8(long_stats.into_inner())
+                .unwrap()
+                .ends_with("expansion ratio: 6.33\n"),
+            "Expansion ratio was not rounded down to two decimal places."
+        );
+
+        let mut empty_stats = Cursor::new(Vec::new());
+        write_stats(&mut empty_stats, 0).unwrap();
+        assert_eq!(
+            "input bytes: 0\noutput characters: 0\nwords: 0\nexpansion ratio: n/a\n",
+            String::from_utf8(empty_stats.into_inner()).unwrap(),
+            "Empty input reported an expansion ratio."
+        );
+
+        let mut output = Cursor::new(Vec::new());
+        let mut convert_stats = Cursor::new(Vec::new());
+        convert(
+            &parse(&[
+                "prbiconv",
+                "encode",
+                "--stats",
+                "--newline",
+                "--separator",
+                "\u{b7}",
+            ]),
+            Cursor::new([0x42_u8, 0x43]),
+            &mut output,
+            &mut convert_stats,
+        )
+        .unwrap();
+        assert_eq!(
+            "flea\u{b7}flux\u{b7}full\n",
+            String::from_utf8(output.into_inner()).unwrap(),
+            "The statistics were written to the output."
+        );
+        assert_eq!(
+            "input bytes: 2\noutput characters: 14\nwords: 3\nexpansion ratio: 7.00\n",
+            String::from_utf8(convert_stats.into_inner()).unwrap(),
+            "The reported number of output characters does not match the output."
+        );
+
+        assert!(
+            Cli::try_parse_from(["prbiconv", "-b", "--stats"]).is_err(),
+            "--stats was accepted without -p."
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let command = parse(&["prbiconv", "verify", "--count"]);
+
+        let input = Cursor::new("turf-port-rust-warn-void\n");
+        let mut output = Cursor::new(Vec::new());
+
+        convert(&command, input, &mut output, io::sink()).unwrap();
+
+        let output_text = String::from_utf8(output.into_inner()).unwrap();
+        assert_eq!(
+            "words: 5\nbytes: 4\n", output_text,
+            "prbiconv incorrectly counted 'turf-port-rust-warn-void'."
+        );
+    }
+
+    #[test]
+    fn test_count_invalid_input() {
+        let command = parse(&["prbiconv", "verify", "--count"]);
+
+        let input = Cursor::new("flux-flea-full");
+        let mut output = Cursor::new(Vec::new());
+        assert!(
+            matches!(
+                convert(&command, input, &mut output, io::sink()),
+                Err(AppError::Decode(DecodeError::CRCError))
+            ),
+            "prbiconv did not report invalid input when counting."
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let command = parse(&["prbiconv", "normalize"]);
+
+        let input = Cursor::new("  FLEA-Flux-fuLL \t\r\n");
+        let mut output = Cursor::new(Vec::new());
+
+        convert(&command, input, &mut output, io::sink()).unwrap();
+
+        let output_words = String::from_utf8(output.into_inner()).unwrap();
+        assert_eq!(
+            "flea-flux-full", output_words,
+            "prbiconv incorrectly normalized '  FLEA-Flux-fuLL '."
+        );
+    }
+
+    #[test]
+    fn test_normalize_invalid_input() {
+        let command = parse(&["prbiconv", "normalize"]);
+
+        let input = Cursor::new("flux-flea-full");
+        let mut output = Cursor::new(Vec::new());
+        assert!(
+            matches!(
+                convert(&command, input, &mut output, io::sink()),
+                Err(AppError::Decode(DecodeError::CRCError))
+            ),
+            "prbiconv did not report invalid input when normalizing."
+        );
+    }
+
+    #[test]
+    fn test_dump() {
+        let command = parse(&["prbiconv", "decode", "--dump"]);
+
+        let input = Cursor::new("flea-flux-full\n");
+        let mut output = Cursor::new(Vec::new());
+
+        convert(&command, input, &mut output, io::sink()).unwrap();
+
+        let output_text = String::from_utf8(output.into_inner()).unwrap();
+        assert_eq!(
+            "00000000  42 43                                             |BC|\n", output_text,
+            "prbiconv incorrectly dumped 'flea-flux-full'."
+        );
+    }
+
+    /// Check that trailing line endings and spaces added by shells and pipes are ignored.
+    #[test]
+    fn test_convert_from_pricklybird_trailing_newline() {
+        for suffix in [
+            "\n", "\r\n", "\r", " ", " \r\n", "\r\n\r\n", "\n\r", "\t \n ",
+        ] {
+            let command = parse(&["prbiconv", "decode"]);
+
+            let input = Cursor::new(format!("flea-flux-full{suffix}"));
+            let mut output = Cursor::new(Vec::new());
+
+            convert(&command, input, &mut output, io::sink()).unwrap();
+
+            assert_eq!(
+                vec![0x42_u8, 0x43],
+                output.into_inner(),
+                "prbiconv failed to convert input with trailing '{}'.",
+                suffix.escape_default()
+            );
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let command = parse(&["prbiconv", "decode", "--strict"]);
+
+        let input = Cursor::new("flea-flux-full");
+        let mut output = Cursor::new(Vec::new());
+
+        convert(&command, input, &mut output, io::sink()).unwrap();
+        assert_eq!(vec![0x42_u8, 0x43], output.into_inner());
+
+        for non_canonical_input in [
+            "FLEA-flux-full",
+            "flea-Flux-full",
+            "flea-flux-full\n",
+            " flea-flux-full",
+        ] {
+            let mut rejected_output = Cursor::new(Vec::new());
+            assert!(
+                matches!(
+                    convert(
+                        &command,
+                        Cursor::new(non_canonical_input),
+                        &mut rejected_output,
+                        io::sink()
+                    ),
+                    Err(AppError::NotCanonical { separator: '-' })
+                ),
+                "prbiconv accepted '{}' with `--strict` set.",
+                non_canonical_input.escape_default()
+            );
+            assert!(rejected_output.into_inner().is_empty());
+        }
+
+        let separator_command = parse(&["prbiconv", "decode", "--strict", "--separator", "."]);
+        let mut separator_output = Cursor::new(Vec::new());
+        convert(
+            &separator_command,
+            Cursor::new("flea.flux.full"),
+            &mut separator_output,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(vec![0x42_u8, 0x43], separator_output.into_inner());
+        let Err(error) = convert(
+            &separator_command,
+            Cursor::new("FLEA.flux.full"),
+            io::sink(),
+            io::sink(),
+        ) else {
+            unreachable!("prbiconv accepted uppercase words with `--strict` set.");
+        };
+        assert_eq!(
+            "Input is not in canonical form, expected lowercase words separated by single '.' without surrounding whitespace.",
+            error.to_string()
+        );
+
+        let json_command = parse(&["prbiconv", "decode", "--strict", "--json"]);
+        assert!(matches!(
+            convert(
+                &json_command,
+                Cursor::new("flea-FLUX-full"),
+                io::sink(),
+                io::sink()
+            ),
+            Err(AppError::NotCanonical { separator: '-' })
+        ));
+    }
+
+    #[test]
+    fn test_json() {
+        let command = parse(&["prbiconv", "decode", "--json"]);
+        assert!(command.json(), "The --json flag was not detected.");
+        assert!(
+            !parse(&["prbiconv", "decode"]).json(),
+            "The --json flag was detected without being set."
+        );
+
+        let mut output = Cursor::new(Vec::new());
+        convert(
+            &command,
+            Cursor::new("FLEA-flux-full\n"),
+            &mut output,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(
+            "{\"payload_hex\":\"4243\",\"crc_ok\":true,\"words\":[\"flea\",\"flux\",\"full\"]}\n",
+            String::from_utf8(output.into_inner()).unwrap()
+        );
+
+        let mut crc_error_output = Cursor::new(Vec::new());
+        convert(
+            &command,
+            Cursor::new("flux-flea-full"),
+            &mut crc_error_output,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(
+            "{\"payload_hex\":\"4342\",\"crc_ok\":false,\"words\":[\"flux\",\"flea\",\"full\"]}\n",
+            String::from_utf8(crc_error_output.into_inner()).unwrap()
+        );
+
+        let mut error_output = Cursor::new(Vec::new());
+        assert!(convert(&command, Cursor::new("flea"), &mut error_output, io::sink()).is_err());
+        assert!(
+            String::from_utf8(error_output.into_inner())
+                .unwrap()
+                .starts_with("{\"error\":\""),
+            "prbiconv did not write the error as JSON."
+        );
+
+        let mut utf8_error_output = Cursor::new(Vec::new());
+        assert!(matches!(
+            convert(
+                &command,
+                Cursor::new([0xff_u8, 0xfe]),
+                &mut utf8_error_output,
+                io::sink()
+            ),
+            Err(AppError::Decode(DecodeError::General(_)))
+        ));
+        assert_eq!(
+            "{\"error\":\"Unable to decode pricklybird words. Input is not valid UTF-8.\"}\n",
+            String::from_utf8(utf8_error_output.into_inner()).unwrap()
+        );
+
+        let mut io_error_output = Cursor::new(Vec::new());
+        assert!(matches!(
+            convert(&command, FailingReader, &mut io_error_output, io::sink()),
+            Err(AppError::Io(_))
+        ));
+        assert!(
+            String::from_utf8(io_error_output.into_inner())
+                .unwrap()
+                .starts_with("{\"error\":\"IO error: "),
+            "prbiconv did not write the IO error as JSON."
+        );
+    }
+
+    #[test]
+    fn test_json_convert_to_pricklybird() {
+        let command = parse(&["prbiconv", "encode", "--json"]);
+
+        let mut output = Cursor::new(Vec::new());
+        convert(
+            &command,
+            Cursor::new([0x42_u8, 0x43]),
+            &mut output,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(
+            "{\"code\":\"flea-flux-full\",\"words\":[\"flea\",\"flux\",\"full\"]}\n",
+            String::from_utf8(output.into_inner()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_convert_lines() {
+        let Command::Decode(args) =
+            parse(&["prbiconv", "decode", "--lines", "--record-separator", ";"])
+        else {
+            unreachable!("The decode subcommand was not parsed as such.");
+        };
+
+        let input = Cursor::new("flea-flux-full\r\n\nflux-flea-full\nflux-flea-call\n");
+        let mut output = Cursor::new(Vec::new());
+        let mut errors = Cursor::new(Vec::new());
+
+        let result = decode_lines(&args, None, input, &mut output, &mut errors);
+        assert!(
+            matches!(result, Err(AppError::LinesFailed(1))),
+            "prbiconv did not report the failed line."
+        );
+        assert_eq!(
+            b"BC;CB;".to_vec(),
+            output.into_inner(),
+            "prbiconv did not decode the valid lines."
+        );
+        assert_eq!(
+            "Line 3: Invalid CRC detected.\n",
+            String::from_utf8(errors.into_inner()).unwrap(),
+            "prbiconv reported the wrong line."
+        );
+    }
+
+    #[test]
+    fn test_convert_lines_fail_fast() {
+        let Command::Decode(args) = parse(&["prbiconv", "decode", "--lines", "--fail-fast"]) else {
+            unreachable!("The decode subcommand was not parsed as such.");
         };
 
-        let input = Cursor::new(Vec::new());
+        let input = Cursor::new("flea-flux-full\nflux-flea-full\nflux-flea-call\n");
         let mut output = Cursor::new(Vec::new());
+        let mut errors = Cursor::new(Vec::new());
 
-        let result = convert(&cli, input, &mut output);
-        assert!(result.is_err());
+        let result = decode_lines(&args, None, input, &mut output, &mut errors);
+        assert!(
+            matches!(
+                result,
+                Err(AppError::Line { line: 2, error })
+                    if matches!(*error, AppError::Decode(DecodeError::CRCError))
+            ),
+            "prbiconv did not stop at the failed line."
+        );
+        assert_eq!(b"BC\n".to_vec(), output.into_inner());
+        assert!(errors.into_inner().is_empty());
+    }
 
+    #[test]
+    fn test_convert_lines_hex() {
+        let Command::Encode(args) = parse(&["prbiconv", "encode", "--lines", "--hex"]) else {
+            unreachable!("The encode subcommand was not parsed as such.");
+        };
+
+        let input = Cursor::new("4243\r\n\n 4342 \n42g3\n424\n");
+        let mut output = Cursor::new(Vec::new());
+        let mut errors = Cursor::new(Vec::new());
+
+        let result = encode_lines(&args, None, input, &mut output, &mut errors);
+        assert!(
+            matches!(result, Err(AppError::LinesFailed(2))),
+            "prbiconv did not report the invalid hexadecimal lines."
+        );
+        assert_eq!(
+            "flea-flux-full\nflux-flea-call\n",
+            String::from_utf8(output.into_inner()).unwrap(),
+            "prbiconv incorrectly converted hexadecimal lines to pricklybird."
+        );
+        assert_eq!(
+            "Line 4: Invalid hexadecimal input. Only the digits 0-9 and a-f are allowed.\n\
+             Line 5: Invalid hexadecimal input. The number of digits must be even.\n",
+            String::from_utf8(errors.into_inner()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(vec![0x0a, 0xff, 0x42], parse_hex(" 0aFF42\n").unwrap());
         assert!(
-            matches!(result, Err(AppError::ArgumentError(_))),
+            parse_hex("").unwrap().is_empty(),
+            "Empty line produced data."
+        );
+        for invalid in ["+f42", "+f", "42+f", "-f42", " 4 2"] {
+            assert!(
+                matches!(parse_hex(invalid), Err(AppError::Hex(_))),
+                "prbiconv accepted the invalid hexadecimal input '{invalid}'."
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_lines_binary_error() {
+        let cli = Cli::try_parse_from(["prbiconv", "-p", "--lines"]).unwrap();
+        assert!(
+            matches!(cli.into_command(), Err(AppError::ArgumentError(_))),
+            "prbiconv did not reject converting raw binary lines."
+        );
+        assert!(
+            Cli::try_parse_from(["prbiconv", "encode", "--lines"]).is_err(),
+            "prbiconv did not reject converting raw binary lines."
+        );
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let cases = [
+            (AppError::Io(io::Error::other("closed")), EXIT_IO),
+            (AppError::Decode(DecodeError::CRCError), EXIT_CRC),
+            (
+                AppError::Decode(DecodeError::EmptyWord { index: 0 }),
+                EXIT_STRUCTURAL,
+            ),
+            (AppError::Hex("Odd length.".to_owned()), EXIT_STRUCTURAL),
+            (AppError::NotCanonical { separator: '-' }, EXIT_STRUCTURAL),
+            (AppError::LinesFailed(2), EXIT_STRUCTURAL),
+            (
+                AppError::ArgumentError("Both flags set.".to_owned()),
+                EXIT_ARGUMENT,
+            ),
+            (
+                AppError::Decode(DecodeError::InvalidSeparator { separator: 'x' }),
+                EXIT_ARGUMENT,
+            ),
+            (
+                AppError::Line {
+                    line: 3,
+                    error: Box::new(AppError::Decode(DecodeError::CRCError)),
+                },
+                EXIT_CRC,
+            ),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(expected, error.exit_code(), "Wrong exit code for: {error}");
+        }
+
+        let mut output = Cursor::new(Vec::new());
+        let crc_error = convert(
+            &parse(&["prbiconv"]),
+            Cursor::new("flux-flea-full"),
+            &mut output,
+            io::sink(),
+        );
+        assert_eq!(
+            Some(EXIT_CRC),
+            crc_error.err().map(|error| error.exit_code())
+        );
+    }
+
+    #[test]
+    fn test_convert_both_flags_error() {
+        let cli = Cli::try_parse_from(["prbiconv", "-b", "-p"]).unwrap();
+
+        assert!(
+            matches!(cli.into_command(), Err(AppError::ArgumentError(_))),
             "prbiconv did not error with both `-p` and `-b` flags set."
         );
     }
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_subcommands() {
+        let encode_command = parse(&["prbiconv", "encode", "--separator", "."]);
+        let mut encoded = Cursor::new(Vec::new());
+        convert(
+            &encode_command,
+            Cursor::new([0x42_u8, 0x43]),
+            &mut encoded,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(b"flea.flux.full".to_vec(), encoded.into_inner());
+
+        let decode_command = parse(&["prbiconv", "decode", "--dump"]);
+        let mut decoded = Cursor::new(Vec::new());
+        convert(
+            &decode_command,
+            Cursor::new("flea-flux-full\n"),
+            &mut decoded,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(
+            format!("{}", HexDump(&[0x42, 0x43])).into_bytes(),
+            decoded.into_inner()
+        );
+
+        let normalize_command = parse(&["prbiconv", "normalize", "-n"]);
+        let mut normalized = Cursor::new(Vec::new());
+        convert(
+            &normalize_command,
+            Cursor::new(" FLEA flux-Full"),
+            &mut normalized,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(b"flea-flux-full\n".to_vec(), normalized.into_inner());
+
+        for invalid in [
+            ["prbiconv", "decode", "--stats"],
+            ["prbiconv", "encode", "--strict"],
+            ["prbiconv", "normalize", "--json"],
+            ["prbiconv", "-p", "encode"],
+        ] {
+            assert!(
+                Cli::try_parse_from(invalid).is_err(),
+                "Arguments '{}' were accepted.",
+                invalid.join(" ")
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let command = parse(&["prbiconv", "verify"]);
+        let mut output = Cursor::new(Vec::new());
+        convert(
+            &command,
+            Cursor::new("flea-flux-full\n"),
+            &mut output,
+            io::sink(),
+        )
+        .unwrap();
+        assert!(
+            output.into_inner().is_empty(),
+            "verify wrote output for a valid pricklybird string."
+        );
+
+        let mut invalid_output = Cursor::new(Vec::new());
+        assert!(
+            matches!(
+                convert(
+                    &command,
+                    Cursor::new("flea-flux-flux"),
+                    &mut invalid_output,
+                    io::sink()
+                ),
+                Err(AppError::Decode(DecodeError::CRCError))
+            ),
+            "verify accepted an incorrect CRC."
+        );
+
+        let count_command = parse(&["prbiconv", "verify", "--count"]);
+        let mut counted = Cursor::new(Vec::new());
+        convert(
+            &count_command,
+            Cursor::new("flea-flux-full"),
+            &mut counted,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(b"words: 3\nbytes: 2\n".to_vec(), counted.into_inner());
+    }
+
+    /// Check that the flags of earlier versions are translated into the equivalent subcommand.
+    #[test]
+    fn test_legacy_flags() {
+        assert!(matches!(
+            parse(&["prbiconv", "-p", "-n", "--separator", "."]),
+            Command::Encode(EncodeArgs {
+                newline: true,
+                separator: Some('.'),
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse(&["prbiconv", "-b", "--strict"]),
+            Command::Decode(DecodeArgs { strict: true, .. })
+        ));
+        assert!(matches!(
+            parse(&["prbiconv", "--dump"]),
+            Command::Decode(DecodeArgs { dump: true, .. })
+        ));
+        assert!(matches!(
+            parse(&["prbiconv", "--count"]),
+            Command::Verify(VerifyArgs { count: true, .. })
+        ));
+        assert!(matches!(
+            parse(&["prbiconv", "--normalize", "-n"]),
+            Command::Normalize(NormalizeArgs { newline: true })
+        ));
+
+        let mut output = Cursor::new(Vec::new());
+        convert(
+            &parse(&["prbiconv", "-p", "-n"]),
+            Cursor::new([0x42_u8, 0x43]),
+            &mut output,
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(b"flea-flux-full\n".to_vec(), output.into_inner());
+
+        let help = Cli::command().render_help().to_string();
+        assert!(
+            help.contains("encode") && !help.contains("--convert-to-pricklybird"),
+            "Legacy flags are shown in the help output."
+        );
+    }
 }
@@ -0,0 +1,113 @@
+//! Benchmarks for the pricklybird conversion functions.
+//!
+//! Uses a minimal timing harness based on `std::time::Instant`,
+//! so no additional dependencies are required.
+//! Heap allocations are counted using a wrapper around the system allocator.
+//! Run using `cargo bench -p pricklybirdlib`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use pricklybirdlib::constants::{BYTE_WORDLIST, HASH_TABLE, word_hash, word_table_lookup};
+use pricklybirdlib::testing::deterministic_bytes;
+use pricklybirdlib::{bytes_to_words, convert_from_pricklybird, convert_to_pricklybird};
+
+/// Seed used to generate benchmark data.
+const BENCH_DATA_SEED: u128 = 1;
+/// How many bytes of pseudorandom data to convert per iteration.
+const BENCH_DATA_BYTES: usize = 1 << 16;
+/// Number of untimed iterations run before measuring.
+const WARMUP_ITERATIONS: u32 = 20;
+/// Number of timed iterations per benchmark.
+const ITERATIONS: u32 = 200;
+
+/// System allocator that counts the number of allocations.
+struct CountingAllocator;
+
+/// Number of allocations made since the program started.
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: All calls are forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+/// Allocator used by the benchmarks, counting allocations.
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Run `routine` repeatedly and print the mean time per iteration and per input byte,
+/// together with the mean number of heap allocations per iteration.
+fn bench(name: &str, input_bytes: usize, mut routine: impl FnMut()) {
+    for _ in 0..WARMUP_ITERATIONS {
+        routine();
+    }
+    let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        routine();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - start_allocations;
+
+    let nanos_per_iteration = elapsed.as_nanos() / u128::from(ITERATIONS);
+    let picos_per_byte = elapsed.as_nanos() * 1000 / (u128::from(ITERATIONS) * input_bytes as u128);
+    let allocations_per_iteration = allocations / ITERATIONS as usize;
+    println!(
+        "{name:<32} {nanos_per_iteration:>12} ns/iter {:>6}.{:03} ns/byte {allocations_per_iteration:>8} allocs/iter",
+        picos_per_byte / 1000,
+        picos_per_byte % 1000
+    );
+}
+
+/// Run all benchmarks.
+fn main() {
+    let data = deterministic_bytes(BENCH_DATA_SEED, BENCH_DATA_BYTES);
+    let code = convert_to_pricklybird(&data);
+
+    bench("bytes_to_words", data.len(), || {
+        let _ = black_box(bytes_to_words(black_box(&data)));
+    });
+    bench("convert_to_pricklybird", data.len(), || {
+        let _ = black_box(convert_to_pricklybird(black_box(&data)));
+    });
+    bench("convert_from_pricklybird", data.len(), || {
+        let _ = black_box(convert_from_pricklybird(black_box(&code)));
+    });
+    // Compare the word lookup using `HASH_TABLE` and `BYTE_WORDLIST`
+    // with the combined `WORD_TABLE` used by the decoders.
+    let words: Vec<[u8; 4]> = bytes_to_words(&data);
+    bench("lookup HASH_TABLE", data.len(), || {
+        for word in black_box(&words) {
+            let byte = HASH_TABLE[word_hash(word[0], word[3])];
+            let _ = black_box((*word == BYTE_WORDLIST[byte as usize]).then_some(byte));
+        }
+    });
+    bench("lookup WORD_TABLE", data.len(), || {
+        for word in black_box(&words) {
+            let _ = black_box(word_table_lookup(*word));
+        }
+    });
+    // Mixed case input exercises the lowercasing of every word.
+    let uppercase_code = code.to_ascii_uppercase();
+    bench("convert_from_pricklybird upper", data.len(), || {
+        let _ = black_box(convert_from_pricklybird(black_box(&uppercase_code)));
+    });
+}
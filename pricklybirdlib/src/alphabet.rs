@@ -0,0 +1,315 @@
+//! Encoding and decoding using custom wordlists.
+//!
+//! An `Alphabet` holds a reference to a wordlist with one four letter word for each byte value,
+//! together with a reverse lookup table derived from it.
+//! This allows experimenting with different wordlists, for example for localization,
+//! while the free functions of the crate keep using the standard wordlist.
+//!
+//! # Usage
+//! ```
+//! use pricklybirdlib::alphabet::Alphabet;
+//! use pricklybirdlib::constants::WORDLIST;
+//! let mut reversed_wordlist = WORDLIST;
+//! reversed_wordlist.reverse();
+//! let alphabet = Alphabet::new(&reversed_wordlist).unwrap();
+//! let code = alphabet.encode(&[0x42, 0x43]);
+//! assert_eq!(vec![0x42, 0x43], alphabet.decode(&code).unwrap());
+//! ```
+
+use std::fmt;
+
+use crate::constants::WORDLIST;
+use crate::{Result, StreamResult, calculate_crc8, decode_with_crc_using, tokens, words_from_utf8};
+
+/// The wordlist supplied to `Alphabet::new` is not a valid pricklybird wordlist.
+///
+/// More specific variants may be added in future releases,
+/// so matches outside of this crate require a wildcard arm.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum AlphabetError {
+    /// A word is not made up of exactly four lowercase ASCII letters.
+    InvalidWord {
+        /// Byte value of the invalid word.
+        index: usize,
+    },
+    /// A word appears more than once in the wordlist.
+    DuplicateWord {
+        /// Byte value of the later occurrence of the word.
+        index: usize,
+    },
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidWord { index } => write!(
+                f,
+                "Invalid wordlist. Word {index} must consist of four lowercase ASCII letters."
+            ),
+            Self::DuplicateWord { index } => {
+                write!(f, "Invalid wordlist. Word {index} appears more than once.")
+            }
+        }
+    }
+}
+
+impl fmt::Debug for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Delegate to Display implementation
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for AlphabetError {}
+
+/// A wordlist mapping each byte value to a unique four letter word.
+///
+/// Provides the same conversions as `convert_to_pricklybird` and `convert_from_pricklybird`,
+/// using this wordlist instead of the standard one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Alphabet<'a> {
+    /// Words indexed by their byte value.
+    words: &'a [&'a str; 256],
+    /// Words as byte arrays paired with their byte value, sorted by word for binary search.
+    reverse: [([u8; 4], u8); 256],
+}
+
+impl<'a> Alphabet<'a> {
+    /// Create an alphabet from a wordlist, deriving the reverse lookup table.
+    ///
+    /// # Errors
+    /// Will return `AlphabetError::InvalidWord` if a word is not made up of
+    /// exactly four lowercase ASCII letters.
+    ///
+    /// Will return `AlphabetError::DuplicateWord` if a word appears more than once,
+    /// since the mapping between bytes and words must be a bijection.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(words: &'a [&'a str; 256]) -> std::result::Result<Self, AlphabetError> {
+        let mut reverse = [([0_u8; 4], 0_u8); 256];
+        for (index, word) in words.iter().enumerate() {
+            let Ok(word_bytes) = <[u8; 4]>::try_from(word.as_bytes()) else {
+                return Err(AlphabetError::InvalidWord { index });
+            };
+            if !word_bytes.iter().all(u8::is_ascii_lowercase) {
+                return Err(AlphabetError::InvalidWord { index });
+            }
+            // There are exactly 256 words, so the index always fits into a byte.
+            reverse[index] = (word_bytes, index as u8);
+        }
+
+        reverse.sort_unstable();
+        if let Some(duplicate) = reverse.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            // Sorting places the lower byte value first.
+            return Err(AlphabetError::DuplicateWord {
+                index: duplicate[1].1 as usize,
+            });
+        }
+        Ok(Self { words, reverse })
+    }
+
+    /// The alphabet using the standard pricklybird `v1` wordlist.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::alphabet::Alphabet;
+    /// let code = Alphabet::standard().encode(&[0x42, 0x43]);
+    /// assert_eq!("flea-flux-full", code);
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn standard() -> Alphabet<'static> {
+        // The standard wordlist is known to be valid.
+        Alphabet::new(&WORDLIST).unwrap()
+    }
+
+    /// The wordlist of this alphabet, indexed by byte value.
+    #[must_use]
+    pub const fn words(&self) -> &'a [&'a str; 256] {
+        self.words
+    }
+
+    /// Look up the byte value of a single word, case insensitive.
+    ///
+    /// Returns `None` if the word does not appear in the wordlist.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::alphabet::Alphabet;
+    /// assert_eq!(Some(0x43), Alphabet::standard().word_to_byte("FLUX"));
+    /// ```
+    #[must_use]
+    pub fn word_to_byte(&self, word: &str) -> Option<u8> {
+        let word_bytes: [u8; 4] = word.as_bytes().try_into().ok()?;
+        self.lowercase_word_to_byte(word_bytes.map(|byte| byte.to_ascii_lowercase()))
+    }
+
+    /// Look up the byte value of a single word that is already lowercase.
+    fn lowercase_word_to_byte(&self, word: [u8; 4]) -> Option<u8> {
+        self.reverse
+            .binary_search_by_key(&word, |&(entry, _)| entry)
+            .ok()
+            .map(|position| self.reverse[position].1)
+    }
+
+    /// Convert arbitrary data to a string of words from this alphabet and attach CRC.
+    ///
+    /// Works like `convert_to_pricklybird`, empty input results in an empty string.
+    #[must_use]
+    pub fn encode(&self, data: &[u8]) -> String {
+        if data.is_empty() {
+            return String::new();
+        }
+        let crc = calculate_crc8(data);
+        let words: Vec<&str> = data
+            .iter()
+            .chain(std::iter::once(&crc))
+            .map(|&byte| self.words[byte as usize])
+            .collect();
+        words.join("-")
+    }
+
+    /// Convert a string of words from this alphabet to bytes and check CRC.
+    ///
+    /// Works like `convert_from_pricklybird`, the conversion is case insensitive.
+    ///
+    /// # Errors
+    /// Will return the same errors as `convert_from_pricklybird`.
+    pub fn decode(&self, words: &str) -> Result<Vec<u8>> {
+        let word_vec: Vec<&str> = tokens(words).collect();
+        decode_with_crc_using(&word_vec, |word| self.lowercase_word_to_byte(word))
+    }
+
+    /// Read all bytes from `input` and write them to `output` as a string of words from this alphabet.
+    ///
+    /// Works like `encode_stream`, `output` is flushed after writing.
+    ///
+    /// # Errors
+    /// Will return any error returned while reading from `input` or writing to `output`.
+    pub fn encode_stream(
+        &self,
+        mut input: impl std::io::Read,
+        mut output: impl std::io::Write,
+    ) -> std::io::Result<()> {
+        let mut data = Vec::new();
+        let _ = input.read_to_end(&mut data)?;
+        output.write_all(self.encode(&data).as_bytes())?;
+        output.flush()
+    }
+
+    /// Read a string of words from this alphabet from `input`, check CRC
+    /// and write the decoded bytes to `output`.
+    ///
+    /// Works like `decode_stream`, nothing is written if the input can not be decoded.
+    /// `output` is flushed after writing.
+    ///
+    /// # Errors
+    /// Will return `StreamError::Decode` with the same errors as `decode`,
+    /// or with `DecodeError::General` if the input is not valid UTF-8.
+    ///
+    /// Will return `StreamError::Io` with any error returned while reading from `input`
+    /// or writing to `output`.
+    pub fn decode_stream(
+        &self,
+        mut input: impl std::io::Read,
+        mut output: impl std::io::Write,
+    ) -> StreamResult<()> {
+        let mut words = Vec::new();
+        let _ = input.read_to_end(&mut words)?;
+        output.write_all(&self.decode(words_from_utf8(&words)?)?)?;
+        output.flush()?;
+        Ok(())
+    }
+}
+
+/// Test conversion using custom wordlists.
+#[cfg(test)]
+mod alphabet_tests {
+    use super::*;
+    use crate::{DecodeError, StreamError, convert_from_pricklybird, convert_to_pricklybird};
+
+    /// Check that the standard alphabet matches the free functions.
+    #[test]
+    fn test_standard_alphabet() {
+        let alphabet = Alphabet::standard();
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        let coded_words = alphabet.encode(&data);
+        assert_eq!(convert_to_pricklybird(&data), coded_words);
+        assert_eq!(data, alphabet.decode(&coded_words).unwrap());
+        assert_eq!(
+            data,
+            convert_from_pricklybird(&coded_words).unwrap(),
+            "Standard alphabet output is not compatible with the free functions."
+        );
+        assert_eq!("", alphabet.encode(&[]));
+    }
+
+    /// Check conversion using a custom wordlist.
+    #[test]
+    fn test_custom_alphabet() {
+        let mut reversed_wordlist = WORDLIST;
+        reversed_wordlist.reverse();
+        let alphabet = Alphabet::new(&reversed_wordlist).unwrap();
+        // The reversed list maps 0x00 to the last word of the standard list.
+        assert_eq!("zone-zone", alphabet.encode(&[0x00]));
+        assert_eq!(vec![0x00], alphabet.decode("ZONE-zone").unwrap());
+
+        let data: Vec<u8> = (0..=u8::MAX).rev().collect();
+        let coded_words = alphabet.encode(&data);
+        assert_eq!(data, alphabet.decode(&coded_words).unwrap());
+        assert!(
+            matches!(
+                alphabet.decode("zone-yoga-zone"),
+                Err(DecodeError::CRCError)
+            ),
+            "Custom alphabet did not detect invalid CRC."
+        );
+        assert!(
+            matches!(alphabet.decode("zone-qqqq"), Err(DecodeError::General(_))),
+            "Custom alphabet did not detect invalid word."
+        );
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 1 }),
+            alphabet.decode("zone--zone")
+        );
+
+        let mut encoded = Vec::new();
+        alphabet
+            .encode_stream(data.as_slice(), &mut encoded)
+            .unwrap();
+        assert_eq!(coded_words.as_bytes(), encoded);
+        let mut decoded = Vec::new();
+        alphabet
+            .decode_stream(encoded.as_slice(), &mut decoded)
+            .unwrap();
+        assert_eq!(data, decoded);
+        assert!(
+            matches!(
+                alphabet.decode_stream(&b"zone-yoga-zone"[..], Vec::new()),
+                Err(StreamError::Decode(DecodeError::CRCError))
+            ),
+            "Custom alphabet stream did not detect invalid CRC."
+        );
+    }
+
+    /// Check that invalid wordlists are rejected.
+    #[test]
+    fn test_invalid_wordlist() {
+        let mut duplicate_wordlist = WORDLIST;
+        duplicate_wordlist[200] = WORDLIST[10];
+        assert_eq!(
+            Err(AlphabetError::DuplicateWord { index: 200 }),
+            Alphabet::new(&duplicate_wordlist)
+        );
+
+        for invalid_word in ["abc", "abcde", "Abcd", "ab1d", "ab d", ""] {
+            let mut invalid_wordlist = WORDLIST;
+            invalid_wordlist[7] = invalid_word;
+            assert_eq!(
+                Err(AlphabetError::InvalidWord { index: 7 }),
+                Alphabet::new(&invalid_wordlist),
+                "Invalid word {invalid_word:?} was accepted."
+            );
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Conversion using the buffers of the `bytes` crate, enabled by the `bytes` feature.
+//!
+//! Intended for network code in the `tokio` and `tower` ecosystem,
+//! where data is already held in `Bytes` and output is collected in `BytesMut`.
+//! Encoding appends the ASCII of the pricklybird string to the buffer directly,
+//! without allocating an intermediate `String`.
+
+use bytes::{Bytes, BytesMut};
+
+use crate::{DecodeError, convert_from_pricklybird_bytes, convert_to_pricklybird_buf};
+
+/// Convert arbitrary data to a pricklybird string with CRC and append its ASCII bytes to `buf`.
+///
+/// The space required for the string is reserved in `buf` before encoding.
+/// Empty input appends nothing.
+///
+/// # Usage
+/// ```
+/// use bytes::BytesMut;
+/// use pricklybirdlib::convert_to_pricklybird_bytes_buf;
+/// let mut buf = BytesMut::from("code: ");
+/// convert_to_pricklybird_bytes_buf(&[0x42, 0x43], &mut buf);
+/// assert_eq!(b"code: flea-flux-full", &buf[..]);
+/// ```
+pub fn convert_to_pricklybird_bytes_buf(data: &[u8], buf: &mut BytesMut) {
+    if data.is_empty() {
+        return;
+    }
+    // Every word takes four characters and is followed by a separator, except for the CRC word.
+    buf.reserve((data.len() + 1) * 5 - 1);
+    convert_to_pricklybird_buf(data, buf);
+}
+
+/// Convert a pricklybird string held in `words` to bytes and check CRC.
+///
+/// Surrounding whitespace such as a trailing newline is accepted, like in `convert_from_pricklybird`.
+/// The decoded data is returned as `Bytes`, so it can be passed on without copying.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird_bytes`.
+///
+/// # Usage
+/// ```
+/// use bytes::Bytes;
+/// use pricklybirdlib::convert_from_pricklybird_bytes_buf;
+/// let data = convert_from_pricklybird_bytes_buf(&Bytes::from_static(b"flea-flux-full")).unwrap();
+/// assert_eq!(Bytes::from_static(&[0x42, 0x43]), data);
+/// ```
+pub fn convert_from_pricklybird_bytes_buf(words: &Bytes) -> Result<Bytes, DecodeError> {
+    convert_from_pricklybird_bytes(words).map(Bytes::from)
+}
+
+/// Test conversion using `Bytes` and `BytesMut`.
+#[cfg(test)]
+mod buffers_tests {
+    use super::*;
+    use crate::{convert_from_pricklybird, convert_to_pricklybird};
+
+    /// Check that the buffer functions agree with the `String` based functions.
+    #[test]
+    fn test_bytes_buf() {
+        let data = [0xDE_u8, 0xAD, 0xBE, 0xEF];
+        let mut buf = BytesMut::new();
+        convert_to_pricklybird_bytes_buf(&data, &mut buf);
+        assert_eq!(convert_to_pricklybird(data).as_bytes(), &buf[..]);
+
+        let decoded = convert_from_pricklybird_bytes_buf(&buf.freeze()).unwrap();
+        assert_eq!(&data[..], &decoded[..]);
+
+        let mut empty_buf = BytesMut::new();
+        convert_to_pricklybird_bytes_buf(&[], &mut empty_buf);
+        assert!(empty_buf.is_empty(), "Empty input appended data.");
+
+        assert_eq!(
+            convert_from_pricklybird("flux-flea-full").map(Bytes::from),
+            convert_from_pricklybird_bytes_buf(&Bytes::from_static(b"flux-flea-full"))
+        );
+    }
+}
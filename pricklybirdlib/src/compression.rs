@@ -0,0 +1,122 @@
+//! Compression of payloads before encoding, enabled by the `flate2` feature.
+//!
+//! Every byte takes up five characters of a pricklybird string,
+//! so deflating compressible payloads such as JSON first results in much shorter codes.
+//! Data that does not compress, like random tokens, gets slightly longer instead,
+//! so compression only helps for compressible data.
+//! The feature adds a dependency on `flate2`.
+//!
+//! Compressed codes start with the word `tiny`, encoding `COMPRESSION_MARKER`,
+//! followed by the raw deflate stream and the CRC word.
+//! The CRC covers the marker and the compressed bytes.
+
+use std::io::{Read as _, Write as _};
+
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+
+use crate::{COMPRESSION_MARKER, DecodeError, convert_from_pricklybird, convert_to_pricklybird};
+
+/// Deflate arbitrary data, then convert it to a pricklybird string and attach CRC.
+///
+/// Use `convert_from_pricklybird_compressed` to decode the result.
+///
+/// # Errors
+/// Will return any error returned while deflating the data.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{convert_from_pricklybird_compressed, convert_to_pricklybird_compressed};
+/// let json = br#"{"items": [{"id": 1}, {"id": 2}, {"id": 3}, {"id": 4}, {"id": 5}]}"#;
+/// let code = convert_to_pricklybird_compressed(json).unwrap();
+/// assert!(code.starts_with("tiny-"));
+/// assert!(code.split('-').count() < json.len());
+/// assert_eq!(json.to_vec(), convert_from_pricklybird_compressed(&code).unwrap());
+/// ```
+pub fn convert_to_pricklybird_compressed(data: &[u8]) -> std::io::Result<String> {
+    let mut encoder = DeflateEncoder::new(vec![COMPRESSION_MARKER], Compression::best());
+    encoder.write_all(data)?;
+    let payload = encoder.finish()?;
+    Ok(convert_to_pricklybird(&payload))
+}
+
+/// Convert a pricklybird string created by `convert_to_pricklybird_compressed` to bytes,
+/// check CRC and inflate the payload.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the payload does not start with `COMPRESSION_MARKER`
+/// or is not a valid deflate stream.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{convert_from_pricklybird_compressed, convert_to_pricklybird_compressed};
+/// let code = convert_to_pricklybird_compressed(&[0x42, 0x43]).unwrap();
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_compressed(&code).unwrap());
+/// assert!(convert_from_pricklybird_compressed("flea-flux-full").is_err());
+/// ```
+pub fn convert_from_pricklybird_compressed(words: &str) -> Result<Vec<u8>, DecodeError> {
+    let payload = convert_from_pricklybird(words)?;
+    let Some((&COMPRESSION_MARKER, compressed)) = payload.split_first() else {
+        return Err(DecodeError::General(
+            "Input does not start with the compression marker.".into(),
+        ));
+    };
+    let mut data = Vec::new();
+    let _ = DeflateDecoder::new(compressed)
+        .read_to_end(&mut data)
+        .map_err(|err| DecodeError::General(format!("Unable to decompress payload. {err}")))?;
+    Ok(data)
+}
+
+/// Test compression before encoding.
+#[cfg(test)]
+mod compression_tests {
+    use super::*;
+    use crate::constants::WORDLIST;
+    use crate::testing::deterministic_bytes;
+
+    /// Check the round trip of compressed codes and that compressible data results in shorter codes.
+    #[test]
+    fn test_compressed() {
+        assert_eq!("tiny", WORDLIST[COMPRESSION_MARKER as usize]);
+
+        let random_data = deterministic_bytes(1, 32);
+        let data = [vec![0_u8; 600], vec![0x42; 20], random_data.clone()].concat();
+        let compressed_words = convert_to_pricklybird_compressed(&data).unwrap();
+        assert!(
+            compressed_words.len() < convert_to_pricklybird(&data).len(),
+            "Compression did not shorten the code."
+        );
+        assert_eq!(
+            data,
+            convert_from_pricklybird_compressed(&compressed_words).unwrap()
+        );
+        for input in [&[][..], &random_data] {
+            assert_eq!(
+                input.to_vec(),
+                convert_from_pricklybird_compressed(
+                    &convert_to_pricklybird_compressed(input).unwrap()
+                )
+                .unwrap()
+            );
+        }
+
+        assert!(matches!(
+            convert_from_pricklybird_compressed("flea-flux-full"),
+            Err(DecodeError::General(_))
+        ));
+        // The marker followed by a truncated deflate stream fails to decompress.
+        let truncated = convert_to_pricklybird([COMPRESSION_MARKER, 0x03]);
+        assert!(matches!(
+            convert_from_pricklybird_compressed(&truncated),
+            Err(DecodeError::General(_))
+        ));
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_compressed("flux-flea-full")
+        );
+    }
+}
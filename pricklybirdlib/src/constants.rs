@@ -59,7 +59,8 @@ const fn convert_wordlist_to_bytes(wordlist: &[&str; 256]) -> [[u8; 4]; 256] {
 ///
 /// Utilizes the fact that all words in the wordlist have unique first, last letter pairs.
 /// Letters are expected to be the in the range `a-z` as lowercase ASCII characters.
-/// Outside that range the index zero is returned.
+/// Outside that range the index zero is returned,
+/// so the result is always a valid index into `HASH_TABLE`.
 ///
 /// # Usage
 /// ```
@@ -78,9 +79,21 @@ pub const fn word_hash(word_first_letter: u8, word_last_letter: u8) -> usize {
     if word_last_letter.wrapping_sub(b'a') >= 26 {
         return 0;
     }
-    (word_first_letter - b'a') as usize + (word_last_letter - b'a') as usize * 26
+    // Both offsets were checked to be in 0..=25 above,
+    // so the index is at most 25 + 25 * 26 = 675, which is below `HASH_TABLE_SIZE`.
+    let index = (word_first_letter - b'a') as usize + (word_last_letter - b'a') as usize * 26;
+    // Catch changes to the formula that break the bound above during testing,
+    // without adding a branch to release builds.
+    debug_assert!(index < HASH_TABLE_SIZE, "Hash index out of bounds.");
+    index
 }
 
+// The largest index `word_hash` can return must fit into `HASH_TABLE`.
+const _: () = assert!(
+    word_hash(b'z', b'z') == HASH_TABLE_SIZE - 1,
+    "HASH_TABLE_SIZE does not match the range of word_hash."
+);
+
 /// Defines the size of the words to bytes table `HASH_TABLE`.
 ///
 /// The highest hash value generated by `word_hash` is 656 for words in the wordlist,
@@ -108,8 +121,233 @@ const fn generate_hash_table(byte_wordlist: &[[u8; 4]; 256]) -> [u8; HASH_TABLE_
 /// Contains the matching byte value.
 pub const HASH_TABLE: [u8; HASH_TABLE_SIZE] = generate_hash_table(&BYTE_WORDLIST);
 
+/// Generate the word table by storing every word together with its byte value
+/// at the position given by `word_hash`.
+#[allow(clippy::cast_possible_truncation)]
+const fn generate_word_table(byte_wordlist: &[[u8; 4]; 256]) -> [([u8; 4], u8); HASH_TABLE_SIZE] {
+    // Empty entries contain no letters, so they never match a validated word.
+    let mut result = [([0_u8; 4], 0_u8); HASH_TABLE_SIZE];
+
+    let mut i = 0;
+    while i < byte_wordlist.len() {
+        let hash_index = word_hash(byte_wordlist[i][0], byte_wordlist[i][3]);
+        result[hash_index] = (byte_wordlist[i], i as u8);
+        i += 1;
+    }
+    result
+}
+
+/// Table indexed by giving the `word_hash` function the words first and last letter.
+/// Contains the expected word in lowercase ASCII and its byte value.
+///
+/// Combines `HASH_TABLE` and `BYTE_WORDLIST`, so looking up a word and verifying
+/// its middle letters only accesses a single entry instead of both tables.
+/// The byte no longer has to be looked up before the expected word can be loaded,
+/// which removes a dependent memory access from every decoded word.
+/// This takes 3380 bytes instead of the 676 bytes of `HASH_TABLE`.
+/// Both fit into the L1 cache, and the lookup alone is not faster:
+/// compare `lookup HASH_TABLE` and `lookup WORD_TABLE` in the `conversion` benchmark.
+/// Entries without a word contain zero bytes, which never match a word of ASCII letters.
+pub(crate) const WORD_TABLE: [([u8; 4], u8); HASH_TABLE_SIZE] = generate_word_table(&BYTE_WORDLIST);
+
+/// Look up the byte value of a lowercase word in `WORD_TABLE`.
+///
+/// Returns `None` if the word does not appear in the wordlist.
+/// Used by the decoders, and public only so the `conversion` benchmark can compare it
+/// with a lookup in `HASH_TABLE` followed by a comparison with `BYTE_WORDLIST`.
+#[doc(hidden)]
+#[must_use]
+#[inline]
+pub fn word_table_lookup(word: [u8; 4]) -> Option<u8> {
+    let (expected_word, byte_value) = WORD_TABLE[word_hash(word[0], word[3])];
+    // Verify that the word stored with the byte matches, this rejects invalid middle letters.
+    (word == expected_word).then_some(byte_value)
+}
+
+/// Details of a `HASH_TABLE` lookup, returned by `word_hash_debug`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct WordHashInfo {
+    /// Index into `HASH_TABLE` computed by `word_hash`.
+    pub hash_index: usize,
+    /// Byte value stored in `HASH_TABLE` at `hash_index`.
+    pub table_byte: u8,
+    /// Whether the word of `table_byte` in `BYTE_WORDLIST` is the looked up word.
+    ///
+    /// If this is `false` the word only shares its first and last letter with a wordlist entry,
+    /// which is why every lookup has to be verified against `BYTE_WORDLIST`.
+    pub matches: bool,
+}
+
+/// Perform the steps of a `HASH_TABLE` lookup and report the intermediate results.
+///
+/// Intended to illustrate the hashing scheme, use `word_to_byte` for actual lookups.
+/// The lookup is case insensitive.
+/// Returns `None` if the word is not four ASCII letters, since `word_hash` is only defined for them.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::constants::{WordHashInfo, word_hash_debug};
+/// let hit = word_hash_debug("flux").unwrap();
+/// assert_eq!(0x43, hit.table_byte);
+/// assert!(hit.matches);
+/// // "flax" shares the first and last letter with "flux", so it hashes to the same slot.
+/// let collision = word_hash_debug("flax").unwrap();
+/// assert_eq!(hit.hash_index, collision.hash_index);
+/// assert!(!collision.matches);
+/// assert_eq!(None, word_hash_debug("flu"));
+/// ```
+#[must_use]
+pub fn word_hash_debug(word: &str) -> Option<WordHashInfo> {
+    let word_bytes: [u8; 4] = word.as_bytes().try_into().ok()?;
+    if !word_bytes.iter().all(u8::is_ascii_alphabetic) {
+        return None;
+    }
+    let word_lower = word_bytes.map(|byte| byte.to_ascii_lowercase());
+    let hash_index = word_hash(word_lower[0], word_lower[3]);
+    let table_byte = HASH_TABLE[hash_index];
+    Some(WordHashInfo {
+        hash_index,
+        table_byte,
+        matches: word_lower == BYTE_WORDLIST[table_byte as usize],
+    })
+}
+
+/// Look up the pricklybird word for a single byte.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::constants::byte_to_word;
+/// assert_eq!("flux", byte_to_word(0x43));
+/// ```
+#[must_use]
+pub const fn byte_to_word(byte: u8) -> &'static str {
+    WORDLIST[byte as usize]
+}
+
+/// Look up the pricklybird word for a single byte as ASCII bytes.
+///
+/// Works like `byte_to_word`, but returns an entry of `BYTE_WORDLIST`,
+/// which allows building lookup tables in const context.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::constants::word_for_byte;
+/// const FLUX: [u8; 4] = word_for_byte(0x43);
+/// assert_eq!(*b"flux", FLUX);
+/// ```
+#[must_use]
+pub const fn word_for_byte(byte: u8) -> [u8; 4] {
+    BYTE_WORDLIST[byte as usize]
+}
+
+/// Look up the byte value of a single pricklybird word.
+///
+/// Performs the complete lookup including the length, ASCII and wordlist checks,
+/// so unlike indexing `HASH_TABLE` directly it is safe to use on arbitrary input.
+/// This lookup is case insensitive.
+/// Returns `None` if the word does not appear in the wordlist.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::constants::word_to_byte;
+/// assert_eq!(Some(0x43), word_to_byte("flux"));
+/// assert_eq!(Some(0x43), word_to_byte("FLUX"));
+/// assert_eq!(None, word_to_byte("flax"));
+/// assert_eq!(None, word_to_byte("zzzz"));
+/// ```
+#[must_use]
+pub fn word_to_byte(word: &str) -> Option<u8> {
+    let word_bytes: [u8; 4] = word.as_bytes().try_into().ok()?;
+    if !word_bytes.is_ascii() {
+        return None;
+    }
+    let word_lower = word_bytes.map(|byte| byte.to_ascii_lowercase());
+    let byte_value = HASH_TABLE[word_hash(word_lower[0], word_lower[3])];
+
+    // Verify that the byte from the lookup operation matches the word.
+    (word_lower == BYTE_WORDLIST[byte_value as usize]).then_some(byte_value)
+}
+
+/// Iterate over all byte values and their matching pricklybird word in ascending byte order.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::constants::wordlist_entries;
+/// let entries: Vec<(u8, &str)> = wordlist_entries().collect();
+/// assert_eq!(256, entries.len());
+/// assert_eq!((0x43, "flux"), entries[0x43]);
+/// ```
+pub fn wordlist_entries() -> impl Iterator<Item = (u8, &'static str)> {
+    (0..=u8::MAX).zip(WORDLIST)
+}
+
 /// Polynominal used in CRC-8 calculation.
 pub const CRC8_POLY: u8 = 0x1D;
+/// Output width of the CRC in bits.
+pub const CRC8_WIDTH: u32 = 8;
+/// Initial value of the CRC register.
+pub const CRC8_INIT: u8 = 0x00;
+/// Whether input bytes are reflected before being processed.
+pub const CRC8_REFLECT_IN: bool = false;
+/// Whether the final CRC is reflected before the output XOR.
+pub const CRC8_REFLECT_OUT: bool = false;
+/// Value combined with the final CRC using XOR.
+pub const CRC8_XOR_OUT: u8 = 0x00;
+/// CRC of the ASCII string `123456789`, commonly used to check implementations.
+pub const CRC8_CHECK: u8 = 0x37;
+/// CRC of data with the correct CRC appended.
+pub const CRC8_RESIDUE: u8 = 0x00;
+
+/// Parameters of the CRC-8 used by pricklybird, returned by `crc8_params`.
+///
+/// Uses the naming of the catalogue of parametrised CRC algorithms,
+/// where these parameters are listed as `CRC-8/GSM-A`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Crc8Params {
+    /// Output width of the CRC in bits.
+    pub width: u32,
+    /// Polynominal used for division, without the leading term.
+    pub polynomial: u8,
+    /// Initial value of the CRC register.
+    pub init: u8,
+    /// Whether input bytes are reflected before being processed.
+    pub reflect_in: bool,
+    /// Whether the final CRC is reflected before the output XOR.
+    pub reflect_out: bool,
+    /// Value combined with the final CRC using XOR.
+    pub xor_out: u8,
+    /// CRC of the ASCII string `123456789`.
+    pub check: u8,
+    /// CRC of data with the correct CRC appended.
+    pub residue: u8,
+}
+
+/// Get the parameters of the CRC-8 used by pricklybird.
+///
+/// Allows checking the configuration of other CRC implementations for compatibility.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::calculate_crc8;
+/// use pricklybirdlib::constants::crc8_params;
+/// let params = crc8_params();
+/// assert_eq!(0x1D, params.polynomial);
+/// assert_eq!(params.check, calculate_crc8(b"123456789"));
+/// ```
+#[must_use]
+pub const fn crc8_params() -> Crc8Params {
+    Crc8Params {
+        width: CRC8_WIDTH,
+        polynomial: CRC8_POLY,
+        init: CRC8_INIT,
+        reflect_in: CRC8_REFLECT_IN,
+        reflect_out: CRC8_REFLECT_OUT,
+        xor_out: CRC8_XOR_OUT,
+        check: CRC8_CHECK,
+        residue: CRC8_RESIDUE,
+    }
+}
+
 /// Number of different possible values in a byte.
 const N_BYTES: usize = 1 << u8::BITS;
 
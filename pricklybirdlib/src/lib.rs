@@ -7,9 +7,9 @@
 //! ## Overview
 //!
 //! [`pricklybird`](https://github.com/ndornseif/pricklybird) is a method for conversion of
-//! arbitrary binary data into more human-friendly words, where each word represents a single byte.  
-//! A CRC-8 checksum is attached to allow the detection of errors during decoding.  
-//! `0xDEADBEEF` becomes `turf-port-rust-warn-void`, for example.  
+//! arbitrary binary data into more human-friendly words, where each word represents a single byte.\
+//! A CRC-8 checksum is attached to allow the detection of errors during decoding.\
+//! `0xDEADBEEF` becomes `turf-port-rust-warn-void`, for example.\
 //!
 //! `pricklybirdlib` is a rust implementation `pricklybird` version `v1`.
 //!
@@ -73,19 +73,114 @@
 /// Contains pricklybird wordlist, reverse wordlist hashmap and CRC-8 lookup table.
 pub mod constants;
 
-use crate::constants::{BYTE_WORDLIST, CRC8_TABLE, HASH_TABLE, word_hash};
+/// Encoding and decoding using custom wordlists.
+pub mod alphabet;
+
+/// Configurable encoding and decoding using builders.
+pub mod options;
+
+/// Pseudorandom data and codes for tests and examples.
+pub mod testing;
+
+/// Conversion using `Bytes` and `BytesMut` buffers.
+#[cfg(feature = "bytes")]
+pub mod buffers;
+
+/// Compression of payloads before encoding.
+#[cfg(feature = "flate2")]
+pub mod compression;
+
+/// Bindings for use from JavaScript.
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "bytes")]
+pub use crate::buffers::{convert_from_pricklybird_bytes_buf, convert_to_pricklybird_bytes_buf};
+#[cfg(feature = "flate2")]
+pub use crate::compression::{
+    convert_from_pricklybird_compressed, convert_to_pricklybird_compressed,
+};
+pub use crate::constants::{byte_to_word, word_to_byte};
+pub use crate::options::{CrcMode, CrcPosition, DecodeOptions, EncodeOptions, WordCase};
+
+use crate::constants::{BYTE_WORDLIST, CRC8_TABLE, WORDLIST, word_table_lookup};
 use std::fmt;
 
 /// Version of the pricklybird specification that this implementation complies with.
 pub const PRICKLYBIRD_VERSION: &str = "v1";
 
+/// Separates the version prefix from the words in versioned pricklybird strings.
+const VERSION_SEPARATOR: char = ':';
+
+/// Separates the words in pricklybird strings created by `convert_to_pricklybird_url`.
+const URL_SEPARATOR: char = '_';
+
+/// Separates the words in pricklybird strings created by `qr_alphanumeric_code`.
+const QR_SEPARATOR: char = ' ';
+
+// The separators above are passed to `EncodeOptions::encode_words`, which does not validate them.
+const _: () = assert!(
+    is_valid_separator(URL_SEPARATOR) && is_valid_separator(QR_SEPARATOR),
+    "Separator constants must be valid separators."
+);
+
+/// Byte placed in front of compressed payloads by `convert_to_pricklybird_compressed`
+/// of the `flate2` feature, encoded as the word `tiny`.
+pub const COMPRESSION_MARKER: u8 = 0xD5;
+
 /// An error occured while trying to decode pricklybird words.
+///
+/// More specific variants may be added in future releases,
+/// so matches outside of this crate require a wildcard arm.
 #[derive(Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum DecodeError {
     /// General decoding error
     General(String),
     /// Invalid CRC
     CRCError,
+    /// The input contains an empty word, caused by a leading,
+    /// trailing or repeated separator.
+    EmptyWord {
+        /// Position of the empty word in the input, starting at zero.
+        index: usize,
+    },
+    /// The input contains a word with characters other than ASCII letters.
+    NonAscii {
+        /// Position of the word in the input, starting at zero.
+        index: usize,
+    },
+    /// The output buffer is too small to hold the decoded data.
+    BufferTooSmall {
+        /// Number of bytes required to hold the decoded data.
+        needed: usize,
+    },
+    /// The version prefix of the input does not match the supported version.
+    VersionMismatch {
+        /// Version prefix found in the input.
+        found: String,
+        /// Version supported by this implementation.
+        expected: &'static str,
+    },
+    /// The separator configured for decoding is rejected by `is_valid_separator`.
+    InvalidSeparator {
+        /// The rejected separator.
+        separator: char,
+    },
+    /// The input contains more words than allowed.
+    TooLong {
+        /// Largest number of words allowed, including the CRC word.
+        max: usize,
+        /// Number of words found in the input, including the CRC word.
+        found: usize,
+    },
+    /// The decoded payload is shorter than required.
+    TooShort {
+        /// Smallest number of payload words required, excluding the CRC word.
+        min: usize,
+        /// Number of payload words found in the input, excluding the CRC word.
+        found: usize,
+    },
 }
 
 impl fmt::Display for DecodeError {
@@ -93,6 +188,85 @@ impl fmt::Display for DecodeError {
         match self {
             Self::General(msg) => write!(f, "Unable to decode pricklybird words. {msg}"),
             Self::CRCError => write!(f, "Invalid CRC detected."),
+            Self::EmptyWord { index } => write!(
+                f,
+                "Unable to decode pricklybird words. Empty word at position {index}, check for extra separators."
+            ),
+            Self::NonAscii { index } => write!(
+                f,
+                "Unable to decode pricklybird words. Word at position {index} contains characters other than ASCII letters."
+            ),
+            Self::BufferTooSmall { needed } => {
+                write!(f, "Output buffer too small, {needed} bytes are required.")
+            }
+            Self::VersionMismatch { found, expected } => write!(
+                f,
+                "Unsupported pricklybird version '{found}', expected '{expected}'."
+            ),
+            Self::InvalidSeparator { separator } => write_invalid_separator(f, *separator),
+            Self::TooLong { max, found } => write!(
+                f,
+                "Input too long, found {found} words but at most {max} are allowed."
+            ),
+            Self::TooShort { min, found } => write!(
+                f,
+                "Input too short, found {found} payload words but at least {min} are required."
+            ),
+        }
+    }
+}
+
+/// Write the message for a separator rejected by `is_valid_separator`,
+/// shared by `DecodeError` and `EncodeError`.
+fn write_invalid_separator(f: &mut fmt::Formatter<'_>, separator: char) -> fmt::Result {
+    write!(
+        f,
+        "Invalid separator '{}', separators must not be letters, digits, control characters, whitespace other than a space or '{VERSION_SEPARATOR}'.",
+        separator.escape_default()
+    )
+}
+
+impl DecodeError {
+    /// Whether the words were read correctly but the CRC does not match.
+    ///
+    /// Such errors are typically caused by a noisy channel or a typo that produced another valid word,
+    /// so reading the input again might help.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::convert_from_pricklybird;
+    /// let error = convert_from_pricklybird("flux-flea-full").unwrap_err();
+    /// assert!(error.is_integrity_error());
+    /// assert!(!error.is_structural_error());
+    /// ```
+    #[must_use]
+    pub const fn is_integrity_error(&self) -> bool {
+        matches!(self, Self::CRCError)
+    }
+
+    /// Whether the input is malformed, for example because it contains unknown words.
+    ///
+    /// `BufferTooSmall` and `InvalidSeparator` are neither structural nor integrity errors,
+    /// since they are caused by the output buffer or configuration rather than the input.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::convert_from_pricklybird;
+    /// let error = convert_from_pricklybird("flea-flax-full").unwrap_err();
+    /// assert!(error.is_structural_error());
+    /// assert!(!error.is_integrity_error());
+    /// ```
+    #[must_use]
+    pub const fn is_structural_error(&self) -> bool {
+        // Listing every variant makes adding a variant without classifying it a compile error.
+        match self {
+            Self::General(_)
+            | Self::EmptyWord { .. }
+            | Self::NonAscii { .. }
+            | Self::VersionMismatch { .. }
+            | Self::TooLong { .. }
+            | Self::TooShort { .. } => true,
+            Self::CRCError | Self::BufferTooSmall { .. } | Self::InvalidSeparator { .. } => false,
         }
     }
 }
@@ -109,6 +283,236 @@ impl std::error::Error for DecodeError {}
 /// Result used in decode functions that can fail.
 type Result<T> = std::result::Result<T, DecodeError>;
 
+/// An error occured while trying to encode data as pricklybird words.
+///
+/// More specific variants may be added in future releases,
+/// so matches outside of this crate require a wildcard arm.
+// Implementing `Copy` would prevent adding variants that hold a message.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The data is longer than the requested length.
+    TooLong {
+        /// Largest number of bytes allowed.
+        max: usize,
+        /// Number of bytes of the data.
+        found: usize,
+    },
+    /// The requested line width is too small to fit a word.
+    LineTooNarrow {
+        /// Smallest line width allowed, in characters.
+        min: usize,
+        /// Requested line width, in characters.
+        found: usize,
+    },
+    /// A separator configured for encoding is rejected by `is_valid_separator`.
+    InvalidSeparator {
+        /// The rejected separator.
+        separator: char,
+    },
+    /// The group separator is the same as the word separator, so groups could not be told apart.
+    AmbiguousGroupSeparator {
+        /// The rejected group separator.
+        separator: char,
+    },
+    /// A field of the data was given a size of zero.
+    EmptyField {
+        /// Position of the field, starting at zero.
+        index: usize,
+    },
+    /// The field sizes do not sum up to the length of the data.
+    FieldSizeMismatch {
+        /// Length of the data in bytes.
+        expected: usize,
+        /// Sum of the field sizes, saturating at `usize::MAX`.
+        found: usize,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong { max, found } => write!(
+                f,
+                "Data too long, found {found} bytes but at most {max} are allowed."
+            ),
+            Self::LineTooNarrow { min, found } => write!(
+                f,
+                "Line width of {found} characters too small, at least {min} are required to fit a word."
+            ),
+            Self::InvalidSeparator { separator } => write_invalid_separator(f, *separator),
+            Self::AmbiguousGroupSeparator { separator } => write!(
+                f,
+                "Group separator '{}' must differ from the word separator.",
+                separator.escape_default()
+            ),
+            Self::EmptyField { index } => {
+                write!(f, "Field at position {index} has a size of zero.")
+            }
+            Self::FieldSizeMismatch { expected, found } => write!(
+                f,
+                "Field sizes sum up to {found} bytes, but the data is {expected} bytes long."
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Delegate to Display implementation
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Result used in encode functions that can fail.
+type EncodeResult<T> = std::result::Result<T, EncodeError>;
+
+/// An error occured while converting data read from a stream, see `decode_stream`.
+///
+/// Separates failures of the reader or writer from failures of the conversion,
+/// so callers can report them differently.
+pub enum StreamError {
+    /// Reading from the input or writing to the output failed.
+    Io(std::io::Error),
+    /// The input could not be decoded.
+    Decode(DecodeError),
+    /// The input could not be encoded.
+    Encode(EncodeError),
+}
+
+impl From<std::io::Error> for StreamError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<DecodeError> for StreamError {
+    fn from(error: DecodeError) -> Self {
+        Self::Decode(error)
+    }
+}
+
+impl From<EncodeError> for StreamError {
+    fn from(error: EncodeError) -> Self {
+        Self::Encode(error)
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "IO error: {err}"),
+            Self::Decode(err) => write!(f, "{err}"),
+            Self::Encode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl fmt::Debug for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Delegate to Display implementation
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Decode(err) => Some(err),
+            Self::Encode(err) => Some(err),
+        }
+    }
+}
+
+/// Result used in stream functions that can fail.
+type StreamResult<T> = std::result::Result<T, StreamError>;
+
+/// Decoded payload together with the result of the CRC check.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DecodeResult {
+    /// Decoded data with the CRC removed.
+    pub payload: Vec<u8>,
+    /// Whether the CRC matched the payload.
+    pub crc_ok: bool,
+}
+
+/// Decoded payload together with both CRC values, returned by `convert_from_pricklybird_tolerant`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TolerantDecodeResult {
+    /// Decoded data with the CRC removed.
+    pub payload: Vec<u8>,
+    /// Whether the CRC matched the payload.
+    pub crc_ok: bool,
+    /// CRC calculated from the decoded payload.
+    pub expected_crc: u8,
+    /// CRC contained in the last word of the input.
+    pub found_crc: u8,
+}
+
+/// A single word of a pricklybird string together with the byte it represents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct DecodedWord {
+    /// The word in its canonical lowercase form.
+    pub word: &'static str,
+    /// Byte value represented by the word.
+    pub byte: u8,
+    /// Whether this is the CRC word rather than part of the payload.
+    pub is_crc: bool,
+}
+
+/// Formats bytes as a hex dump with offset, hex and ASCII columns.
+///
+/// Each line shows 16 bytes, bytes outside of printable ASCII are shown as `.`.
+/// Every line including the last ends with a newline, empty data produces no output.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{HexDump, convert_from_pricklybird};
+/// let data = convert_from_pricklybird("flea-flux-full").unwrap();
+/// assert_eq!(
+///     "00000000  42 43                                             |BC|\n",
+///     HexDump(&data).to_string()
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct HexDump<'a>(pub &'a [u8]);
+
+/// Number of bytes shown on each line of a `HexDump`.
+const HEX_DUMP_LINE_BYTES: usize = 16;
+
+impl fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line_index, line) in self.0.chunks(HEX_DUMP_LINE_BYTES).enumerate() {
+            write!(f, "{:08x} ", line_index * HEX_DUMP_LINE_BYTES)?;
+            for column in 0..HEX_DUMP_LINE_BYTES {
+                // Separate the two halves of the line.
+                if column % 8 == 0 {
+                    write!(f, " ")?;
+                }
+                match line.get(column) {
+                    Some(byte) => write!(f, "{byte:02x} ")?,
+                    None => write!(f, "   ")?,
+                }
+            }
+            write!(f, " |")?;
+            for &byte in line {
+                let printable = if byte.is_ascii_graphic() || byte == b' ' {
+                    char::from(byte)
+                } else {
+                    '.'
+                };
+                write!(f, "{printable}")?;
+            }
+            writeln!(f, "|")?;
+        }
+        Ok(())
+    }
+}
+
 /// Calculate the CRC-8 used by pricklybird based on a precomputed table.
 ///
 /// # CRC parameters
@@ -119,6 +523,8 @@ type Result<T> = std::result::Result<T, DecodeError>;
 /// - No XOR operation on the output
 /// - Remainder after division of data with correct CRC appended is zero
 ///
+/// The parameters are also available programmatically using `constants::crc8_params`.
+///
 /// # Usage
 /// ```
 /// use pricklybirdlib::calculate_crc8;
@@ -128,12 +534,243 @@ type Result<T> = std::result::Result<T, DecodeError>;
 #[must_use]
 #[inline]
 pub fn calculate_crc8(data: &[u8]) -> u8 {
-    let mut crc = 0_u8;
+    calculate_crc8_with_init(data, 0)
+}
 
-    for &byte in data {
-        crc = CRC8_TABLE[(crc ^ byte) as usize];
+/// Calculate a CRC-8 with the polynomial `0x1D`, starting the CRC register at `init`.
+///
+/// Allows interoperating with other CRC-8 variants using the same polynomial,
+/// such as CRC-8/HITAG with an initial value of `0xFF`.
+/// The other parameters are the same as for `calculate_crc8`,
+/// which is equivalent to an initial value of zero as required by the pricklybird specification.
+/// Pricklybird strings always use `calculate_crc8`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{calculate_crc8, calculate_crc8_with_init};
+/// assert_eq!(calculate_crc8(b"123456789"), calculate_crc8_with_init(b"123456789", 0x00));
+/// assert_eq!(0xB4, calculate_crc8_with_init(b"123456789", 0xFF));
+/// ```
+#[must_use]
+#[inline]
+pub fn calculate_crc8_with_init(data: &[u8], init: u8) -> u8 {
+    let mut crc = Crc8 { state: init };
+    crc.update(data);
+    crc.finalize()
+}
+
+/// Streaming calculation of the CRC-8 used by pricklybird.
+///
+/// Allows calculating the CRC of data that arrives in multiple chunks.
+/// Uses the same parameters as `calculate_crc8`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{Crc8, calculate_crc8};
+/// let mut crc = Crc8::new();
+/// crc.update(b"1234");
+/// crc.update(b"56789");
+/// assert_eq!(calculate_crc8(b"123456789"), crc.finalize());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Crc8 {
+    /// Current value of the CRC register.
+    state: u8,
+}
+
+impl Crc8 {
+    /// Create a new CRC-8 accumulator with the initial value of zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { state: 0 }
+    }
+
+    /// Feed `data` into the CRC calculation.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.state = CRC8_TABLE[(self.state ^ byte) as usize];
+        }
+    }
+
+    /// Return the CRC-8 of all data supplied so far.
+    #[must_use]
+    pub const fn finalize(self) -> u8 {
+        self.state
+    }
+}
+
+/// The CRC of a payload together with the intermediate CRC register values, returned by `crc_breakdown`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CrcBreakdown {
+    /// The CRC of the payload, as calculated by `calculate_crc8`.
+    pub crc: u8,
+    /// The word encoding the CRC, which is appended to the payload words.
+    pub word: &'static str,
+    /// Value of the CRC register after each payload byte, the last value is `crc`.
+    pub steps: Vec<u8>,
+}
+
+/// Calculate the CRC of `data` and record the CRC register after each byte.
+///
+/// Intended for visualizing how the CRC word depends on the payload.
+/// Use `calculate_crc8` if only the CRC is needed, which does not allocate.
+/// Empty input results in a CRC of zero without any steps.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{calculate_crc8, crc_breakdown};
+/// let breakdown = crc_breakdown(&[0x42, 0x43]);
+/// assert_eq!(calculate_crc8(&[0x42, 0x43]), breakdown.crc);
+/// assert_eq!("full", breakdown.word);
+/// assert_eq!(vec![calculate_crc8(&[0x42]), breakdown.crc], breakdown.steps);
+/// ```
+#[must_use]
+pub fn crc_breakdown(data: &[u8]) -> CrcBreakdown {
+    let mut crc = Crc8::new();
+    let steps: Vec<u8> = data
+        .iter()
+        .map(|&byte| {
+            crc.update(&[byte]);
+            crc.finalize()
+        })
+        .collect();
+    CrcBreakdown {
+        crc: crc.finalize(),
+        word: byte_to_word(crc.finalize()),
+        steps,
+    }
+}
+
+/// Errors the CRC-8 used by pricklybird is guaranteed to detect, returned by `crc_guarantees`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct CrcGuarantees {
+    /// Smallest number of flipped bits that can go undetected.
+    pub hamming_distance: u8,
+    /// Any error flipping up to this many bits is detected, one less than `hamming_distance`.
+    pub detected_bit_errors: u8,
+    /// Any error confined to this many consecutive bits is detected.
+    pub detected_burst_bits: u8,
+    /// Any error confined to a single word is detected.
+    pub detects_single_word_errors: bool,
+    /// Swapping any two adjacent different words is detected.
+    pub detects_adjacent_swaps: bool,
+}
+
+/// Hamming distance of the CRC-8 with polynomial `0x1D` by payload length.
+///
+/// Each entry contains the largest payload length in bytes that has the Hamming distance,
+/// with the CRC word appended. Computed by searching for the lightest undetected bit error.
+/// The polynomial is primitive with period 255, so from 31 payload bytes on, a codeword has 256 bits
+/// and two flipped bits 255 positions apart go undetected.
+const CRC8_HAMMING_DISTANCES: [(usize, u8); 3] = [(1, 4), (30, 3), (usize::MAX, 2)];
+
+/// Report the errors the CRC-8 is guaranteed to detect for codes with a payload of `payload_len` bytes.
+///
+/// Errors affecting a single word, bursts of up to 8 bits and swaps of adjacent words
+/// are detected at any length.
+/// Arbitrary bit errors are only reliably detected up to `detected_bit_errors` flipped bits,
+/// which shrinks as the payload grows.
+/// Other errors, such as two corrupted words, are detected with a probability of about 255 in 256.
+/// Payloads of length zero are treated like payloads of one byte.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::crc_guarantees;
+/// assert_eq!(2, crc_guarantees(16).detected_bit_errors);
+/// assert_eq!(1, crc_guarantees(64).detected_bit_errors);
+/// assert!(crc_guarantees(64).detects_single_word_errors);
+/// ```
+#[must_use]
+pub fn crc_guarantees(payload_len: usize) -> CrcGuarantees {
+    let hamming_distance = CRC8_HAMMING_DISTANCES
+        .iter()
+        .find(|&&(max_len, _)| payload_len <= max_len)
+        .map_or(2, |&(_, distance)| distance);
+    CrcGuarantees {
+        hamming_distance,
+        detected_bit_errors: hamming_distance - 1,
+        detected_burst_bits: 8,
+        detects_single_word_errors: true,
+        detects_adjacent_swaps: true,
+    }
+}
+
+/// Estimated resistance of a code to human transcription errors, returned by `transcription_safety`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TranscriptionSafety {
+    /// Number of words a human has to transcribe, including the CRC word.
+    pub words: usize,
+    /// Probability that substituting a single word with a random other word goes undetected.
+    ///
+    /// Always zero, since such errors change at most 8 consecutive bits.
+    pub single_word_undetected: f64,
+    /// Approximate probability that random substitutions of two or more words go undetected.
+    ///
+    /// Such errors produce an essentially random codeword,
+    /// which has the correct CRC with a probability of 1 in 256.
+    pub multi_word_undetected: f64,
+    /// Whether CRC-8 is considered adequate for this payload length.
+    ///
+    /// True if any three flipped bits are still detected, which holds for up to 30 payload bytes.
+    /// Longer codes see more multi word errors, consider splitting them using `encode_chunked`.
+    pub crc8_adequate: bool,
+}
+
+/// Estimate how well the CRC-8 protects codes with a payload of `byte_len` bytes against transcription errors.
+///
+/// Builds on `crc_guarantees` to help choosing the length of human entered codes,
+/// such as recovery codes.
+/// Single word errors and swaps of adjacent words are always detected,
+/// other errors affecting several words go undetected with a probability of about 1 in 256.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::transcription_safety;
+/// let safety = transcription_safety(16);
+/// assert_eq!(17, safety.words);
+/// assert_eq!(0.0, safety.single_word_undetected);
+/// assert!(safety.crc8_adequate);
+/// assert!(!transcription_safety(64).crc8_adequate);
+/// ```
+#[must_use]
+pub fn transcription_safety(byte_len: usize) -> TranscriptionSafety {
+    let guarantees = crc_guarantees(byte_len);
+    TranscriptionSafety {
+        words: encoded_words_len(byte_len),
+        single_word_undetected: if guarantees.detects_single_word_errors {
+            0.0
+        } else {
+            1.0 / 256.0
+        },
+        multi_word_undetected: 1.0 / 256.0,
+        crc8_adequate: guarantees.hamming_distance >= 3,
+    }
+}
+
+/// Check whether `data` consists of at least two bytes that are all equal.
+///
+/// Such payloads produce visually repetitive codes that are valid, but often mistaken for bugs,
+/// for example all zeros become `acid-acid-acid-acid-acid-acid` since the CRC of zeros is zero.
+/// Intended as an advisory check, so user interfaces can point out that the data looks degenerate.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{convert_to_pricklybird, is_degenerate};
+/// let zeros = [0_u8; 5];
+/// assert!(is_degenerate(&zeros));
+/// assert_eq!("acid-acid-acid-acid-acid-acid", convert_to_pricklybird(&zeros));
+/// assert!(is_degenerate(&[0xFF; 5]));
+/// assert_eq!("zone-zone-zone-zone-zone-sand", convert_to_pricklybird(&[0xFF; 5]));
+/// assert!(!is_degenerate(&[0x42, 0x43]));
+/// assert!(!is_degenerate(&[0x42]));
+/// ```
+#[must_use]
+pub fn is_degenerate(data: &[u8]) -> bool {
+    match data {
+        [first, rest @ ..] if !rest.is_empty() => rest.iter().all(|byte| byte == first),
+        _ => false,
     }
-    crc
 }
 
 /// Convert bytearray to list of pricklybird words.
@@ -150,12 +787,103 @@ pub fn calculate_crc8(data: &[u8]) -> u8 {
 /// ```
 #[must_use]
 pub fn bytes_to_words(data: &[u8]) -> Vec<[u8; 4]> {
-    let mut result_words = Vec::with_capacity(data.len());
+    // Collecting from the slice iterator allows the exact allocation size to be known
+    // upfront, avoiding the capacity check a `push` per byte would require.
+    // Indexing the 256 entry wordlist with a `u8` can not go out of bounds,
+    // so no bounds check is emitted and `get_unchecked` would not be any faster.
+    data.iter()
+        .map(|&byte| BYTE_WORDLIST[byte as usize])
+        .collect()
+}
 
-    for &byte in data {
-        result_words.push(BYTE_WORDLIST[byte as usize]);
+/// Convert bytearray to list of pricklybird words and the CRC word, returned separately.
+///
+/// Works like `bytes_to_words`, additionally returning the word for the CRC of `data`
+/// that `convert_to_pricklybird` appends to the payload.
+/// Allows rendering the CRC word differently without splitting a joined string.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::bytes_to_words_with_crc;
+/// let (words, crc) = bytes_to_words_with_crc(&[0x42, 0x43]);
+/// assert_eq!(vec![*b"flea", *b"flux"], words);
+/// assert_eq!(*b"full", crc);
+/// ```
+#[must_use]
+pub fn bytes_to_words_with_crc(data: &[u8]) -> (Vec<[u8; 4]>, [u8; 4]) {
+    let crc = calculate_crc8(data);
+    (bytes_to_words(data), BYTE_WORDLIST[crc as usize])
+}
+
+/// Convert bytearray to list of pricklybird words as string slices.
+///
+/// Works like `bytes_to_words`, but returns the words as string slices into `WORDLIST`.
+/// Since the wordlist is static, the returned slices are valid for the entire
+/// runtime of the program and no allocation per word is required.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::bytes_to_word_strs;
+/// let words = bytes_to_word_strs(&[0x42, 0x43]);
+/// assert_eq!(vec!["flea", "flux"], words);
+/// ```
+#[must_use]
+pub fn bytes_to_word_strs(data: &[u8]) -> Vec<&'static str> {
+    data.iter().map(|&byte| WORDLIST[byte as usize]).collect()
+}
+
+/// Check whether `c` can be used to separate the words of a pricklybird string.
+///
+/// Letters are rejected since they would collide with the wordlist,
+/// which is decoded case insensitive.
+/// Digits are rejected since `convert_from_pricklybird_flexible` treats them as part of a word.
+/// A space is allowed, since codes are commonly written as space separated words,
+/// but other whitespace and control characters are rejected,
+/// since they are easily altered by line based processing or copy and paste.
+/// The version separator `:` is rejected, since it would make versioned strings ambiguous.
+///
+/// `EncodeOptions` and `DecodeOptions` use this to validate their separator.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::is_valid_separator;
+/// assert!(is_valid_separator('-'));
+/// assert!(is_valid_separator(' '));
+/// assert!(!is_valid_separator('x'));
+/// assert!(!is_valid_separator('0'));
+/// assert!(!is_valid_separator('\n'));
+/// ```
+#[must_use]
+pub const fn is_valid_separator(c: char) -> bool {
+    c == ' '
+        || !(c.is_ascii_alphanumeric()
+            || c.is_whitespace()
+            || c.is_ascii_control()
+            || c == VERSION_SEPARATOR)
+}
+
+/// Convert bytearray to pricklybird words joined by `sep`, without attaching a CRC.
+///
+/// Unlike `convert_to_pricklybird` the result contains no integrity check,
+/// so errors in the words can not be detected when decoding.
+/// Empty input results in an empty string.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::bytes_to_pricklybird_string;
+/// assert_eq!("flea-flux", bytes_to_pricklybird_string(&[0x42, 0x43], '-'));
+/// assert_eq!("flea flux", bytes_to_pricklybird_string(&[0x42, 0x43], ' '));
+/// ```
+#[must_use]
+pub fn bytes_to_pricklybird_string(data: &[u8], sep: char) -> String {
+    let mut words = String::with_capacity(data.len() * (4 + sep.len_utf8()));
+    for (index, &byte) in data.iter().enumerate() {
+        if index != 0 {
+            words.push(sep);
+        }
+        words.push_str(WORDLIST[byte as usize]);
     }
-    result_words
+    words
 }
 
 /// Return a vector of bytes coresponding to the pricklybird words supplied as input.
@@ -164,10 +892,13 @@ pub fn bytes_to_words(data: &[u8]) -> Vec<[u8; 4]> {
 ///
 /// # Errors
 /// Will return `DecodeError::General` if:
-/// - The input contains non ASCII compatible characters
 /// - The words in the input are not all four characters long
 /// - Words in the input dont appear in the wordlist
 ///
+/// Will return `DecodeError::EmptyWord` if one of the words is empty.
+///
+/// Will return `DecodeError::NonAscii` if a word contains characters other than ASCII letters.
+///
 /// # Usage
 /// ```
 /// use pricklybirdlib::words_to_bytes;
@@ -175,261 +906,4378 @@ pub fn bytes_to_words(data: &[u8]) -> Vec<[u8; 4]> {
 /// let data = words_to_bytes(&words).unwrap();
 /// assert_eq!(vec![0x42, 0x43], data);
 /// ```
-pub fn words_to_bytes(words: &Vec<&str>) -> Result<Vec<u8>> {
-    let mut bytevector = Vec::<u8>::with_capacity(words.len());
+pub fn words_to_bytes(words: &[&str]) -> Result<Vec<u8>> {
+    words_to_bytes_using(words, word_table_lookup)
+}
 
-    for &word in words {
-        let word_lower = word.to_lowercase();
-        let word_bytes = word_lower.as_bytes();
-        if word_bytes.len() != 4 {
-            return Err(DecodeError::General(
-                "Input words must be four characters long.".into(),
-            ));
-        }
-        let recovered_byte = HASH_TABLE[word_hash(word_bytes[0], word_bytes[3])];
+/// Convert words to bytes like `words_to_bytes`, looking up each word using `lookup`.
+///
+/// `lookup` receives the validated word in lowercase, which allows sharing
+/// the validation with wordlists other than the standard one.
+fn words_to_bytes_using(words: &[&str], lookup: impl Fn([u8; 4]) -> Option<u8>) -> Result<Vec<u8>> {
+    let mut bytevector = Vec::<u8>::with_capacity(words.len());
 
-        // Verify that the byte from the lookup operation matches the word.
-        if word_bytes != BYTE_WORDLIST[recovered_byte as usize] {
-            return Err(DecodeError::General(
-                "Invalid word detected in input.".into(),
-            ));
-        }
-        bytevector.push(recovered_byte);
+    for (index, &word) in words.iter().enumerate() {
+        bytevector.push(decode_word_using(word, index, &lookup)?);
     }
     Ok(bytevector)
 }
 
+/// Check that a word is structurally valid before it is looked up in the wordlist.
+///
+/// All decode functions must validate words using this function,
+/// `index` is the position of the word in the input and used in errors.
+/// Since only ASCII letters are accepted, this also rejects control characters
+/// such as null bytes and DEL, whitespace and any byte with the high bit set.
+/// Returns the four letters of the valid word.
+fn validate_word_bytes(word: &[u8], index: usize) -> Result<[u8; 4]> {
+    if word.is_empty() {
+        return Err(DecodeError::EmptyWord { index });
+    }
+    // Only ASCII letters may appear in words. Checking this before lowercasing
+    // prevents confusable Unicode characters from being folded into valid words,
+    // such as the Kelvin sign `\u{212a}` which lowercases to an ASCII `k`.
+    if !word.iter().all(u8::is_ascii_alphabetic) {
+        return Err(DecodeError::NonAscii { index });
+    }
+    word.try_into()
+        .map_err(|_| DecodeError::General("Input words must be four characters long.".into()))
+}
+
+/// Validate a single word and look up its byte value.
+fn decode_word(word: &str, index: usize) -> Result<u8> {
+    decode_word_using(word, index, word_table_lookup)
+}
+
+/// Validate a single word and look up its byte value using `lookup`.
+fn decode_word_using(
+    word: &str,
+    index: usize,
+    lookup: impl FnOnce([u8; 4]) -> Option<u8>,
+) -> Result<u8> {
+    // Validation only accepts four ASCII letters, so lowercasing a copy on the stack
+    // is sufficient and avoids allocating a string for Unicode case folding.
+    let mut word_bytes = validate_word_bytes(word.as_bytes(), index)?;
+    word_bytes.make_ascii_lowercase();
+    lookup(word_bytes).ok_or_else(|| DecodeError::General("Invalid word detected in input.".into()))
+}
+
 /// Convert arbitrary data to a pricklybird string and attach CRC.
 ///
+/// Accepts anything that can be viewed as a byte slice,
+/// such as slices, arrays, vectors or strings.
+/// Empty input results in an empty string.
+///
 /// # Usage
 /// ```
 /// use pricklybirdlib::convert_to_pricklybird;
 /// let data = [0x42_u8, 0x43];
 /// let code = convert_to_pricklybird(&data);
 /// assert_eq!("flea-flux-full", code);
+/// assert_eq!("flea-flux-full", convert_to_pricklybird(vec![0x42_u8, 0x43]));
+/// assert_eq!("flea-flux-full", convert_to_pricklybird("BC"));
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird<T: AsRef<[u8]>>(data: T) -> String {
+    encode_with_crc(data.as_ref())
+}
+
+/// Convert arbitrary data to a pricklybird string and attach CRC, without any possibility of panicking.
+///
+/// Works like `convert_to_pricklybird`, which is implemented without `unwrap` or other panics,
+/// so this never returns an error.
+/// The fallible signature is intended for defensive callers that treat every conversion
+/// as fallible, for example services that must not abort.
+///
+/// # Errors
+/// Currently never returns an error.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::try_convert_to_pricklybird;
+/// assert_eq!(Ok("flea-flux-full".to_owned()), try_convert_to_pricklybird(&[0x42, 0x43]));
+/// ```
+pub fn try_convert_to_pricklybird(data: &[u8]) -> EncodeResult<String> {
+    Ok(encode_with_crc(data))
+}
+
+/// Convert arbitrary data to a pricklybird string and attach CRC,
+/// returning the code together with the CRC byte that was appended.
+///
+/// Useful for logging the CRC without recomputing it using `calculate_crc8`.
+/// Empty input results in an empty string and a CRC of zero, the CRC of no data.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{calculate_crc8, convert_to_pricklybird_with_crc_byte};
+/// let (code, crc) = convert_to_pricklybird_with_crc_byte(&[0x42, 0x43]);
+/// assert_eq!("flea-flux-full", code);
+/// assert_eq!(calculate_crc8(&[0x42, 0x43]), crc);
 /// ```
-#[allow(clippy::missing_panics_doc)]
 #[must_use]
-pub fn convert_to_pricklybird(data: &[u8]) -> String {
+pub fn convert_to_pricklybird_with_crc_byte(data: &[u8]) -> (String, u8) {
     if data.is_empty() {
-        return String::new();
+        return (String::new(), 0);
     }
     let crc = calculate_crc8(data);
     let mut data_with_crc = Vec::with_capacity(data.len() + 1);
     data_with_crc.extend_from_slice(data);
     data_with_crc.push(crc);
+    (join_words(&data_with_crc), crc)
+}
 
-    // Unwrap is safe here since we know the wordlist and seperator are valid UTF-8.
-    String::from_utf8(bytes_to_words(&data_with_crc).join(&b'-')).unwrap()
+/// Non generic implementation of `convert_to_pricklybird`.
+fn encode_with_crc(data: &[u8]) -> String {
+    convert_to_pricklybird_with_crc_byte(data).0
 }
 
-/// Convert a pricklybird string to bytes and check CRC.
+/// Convert bytes to pricklybird words joined by `-`, without attaching a CRC.
+fn join_words(data: &[u8]) -> String {
+    // Building the string from the wordlist avoids a fallible UTF-8 conversion.
+    bytes_to_pricklybird_string(data, '-')
+}
+
+/// Pad data with `pad` bytes to `target_len` bytes and convert it to a pricklybird string with CRC.
 ///
-/// # Errors
-/// Will return `DecodeError::General` if:
-/// - The input is less than two words long,
-/// - The input contains non ASCII compatible characters
-/// - The words in the input are not all four characters long
-/// - Words in the input dont appear in the wordlist
+/// All codes produced with the same `target_len` have the same number of words,
+/// which helps aligning them in user interfaces and databases.
+/// The CRC is calculated over the padded data.
+/// The padding is not marked in the output, decoding returns the padded data.
+/// Removing the padding requires knowing the convention out of band,
+/// e.g. a pad byte that never appears at the end of the actual data.
 ///
-/// Will return `DecodeError::CRCError` if the CRC value does not match the input.
+/// # Errors
+/// Will return `EncodeError::TooLong` if `data` is longer than `target_len`.
 ///
 /// # Usage
 /// ```
-/// use pricklybirdlib::convert_from_pricklybird;
-/// let code = "flea-flux-full";
-/// let data = convert_from_pricklybird(code).unwrap();
-/// assert_eq!(vec![0x42, 0x43], data);
+/// use pricklybirdlib::{convert_from_pricklybird, convert_to_pricklybird_padded};
+/// let code = convert_to_pricklybird_padded(&[0x42], 3, 0x00).unwrap();
+/// assert_eq!(4, code.split('-').count());
+/// assert_eq!(vec![0x42, 0x00, 0x00], convert_from_pricklybird(&code).unwrap());
+/// assert!(convert_to_pricklybird_padded(&[0x42, 0x43], 1, 0x00).is_err());
 /// ```
-pub fn convert_from_pricklybird(words: &str) -> Result<Vec<u8>> {
-    let word_vec: Vec<&str> = words.trim().split('-').collect();
-
-    if word_vec.len() < 2 {
+pub fn convert_to_pricklybird_padded(
+    data: &[u8],
+    target_len: usize,
+    pad: u8,
+) -> EncodeResult<String> {
+    if data.len() > target_len {
+        return Err(EncodeError::TooLong {
+            max: target_len,
+            found: data.len(),
+        });
+    }
+    let mut padded_data = Vec::with_capacity(target_len);
+    padded_data.extend_from_slice(data);
+    padded_data.resize(target_len, pad);
+    Ok(encode_with_crc(&padded_data))
+}
+
+/// Convert arbitrary data to a pricklybird string with CRC, split into lines of at most `max_width` characters.
+///
+/// Lines are only broken between words, the separator at each line break is left out.
+/// Joining the lines with `-` results in the output of `convert_to_pricklybird`,
+/// joining them with newlines results in a code accepted by `convert_from_pricklybird_flexible`.
+/// Every line except the last holds `(max_width + 1) / 5` words.
+/// Empty input results in no lines.
+///
+/// # Errors
+/// Will return `EncodeError::LineTooNarrow` if `max_width` is less than four, since no word would fit.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_wrapped;
+/// let lines = convert_to_pricklybird_wrapped(&[0x42, 0x43], 9).unwrap();
+/// assert_eq!(vec!["flea-flux", "full"], lines);
+/// ```
+pub fn convert_to_pricklybird_wrapped(data: &[u8], max_width: usize) -> EncodeResult<Vec<String>> {
+    if max_width < 4 {
+        return Err(EncodeError::LineTooNarrow {
+            min: 4,
+            found: max_width,
+        });
+    }
+    if data.is_empty() {
+        return Ok(Vec::new());
+    }
+    // Each word takes four characters plus one separator, except the last word of a line.
+    let words_per_line = (max_width + 1) / 5;
+    let mut words = bytes_to_word_strs(data);
+    words.push(crc_word(data));
+    Ok(words
+        .chunks(words_per_line)
+        .map(|line| line.join("-"))
+        .collect())
+}
+
+/// Convert arbitrary data to a pricklybird string with CRC, grouping the words by field.
+///
+/// The payload is split into consecutive fields of `field_sizes` bytes.
+/// Words within a field are separated by `-`, fields by `group_separator`,
+/// and the CRC word is appended as its own field.
+/// This helps reading structured records, such as the parts of an address.
+/// Empty input with no fields results in an empty string.
+/// Use `convert_from_pricklybird_flexible` to decode the result.
+///
+/// # Errors
+/// Will return:
+/// - `EncodeError::AmbiguousGroupSeparator` if `group_separator` is `-`
+/// - `EncodeError::InvalidSeparator` if `group_separator` is rejected by `is_valid_separator`
+/// - `EncodeError::EmptyField` if a field size is zero
+/// - `EncodeError::FieldSizeMismatch` if the field sizes do not sum up to the length of `data`
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{convert_from_pricklybird_flexible, convert_to_pricklybird_fielded};
+/// let data = [0x42, 0x43, 0x42];
+/// let code = convert_to_pricklybird_fielded(&data, &[1, 2], ' ').unwrap();
+/// assert_eq!("flea flux-flea kale", code);
+/// assert_eq!(data.to_vec(), convert_from_pricklybird_flexible(&code).unwrap());
+/// ```
+pub fn convert_to_pricklybird_fielded(
+    data: &[u8],
+    field_sizes: &[usize],
+    group_separator: char,
+) -> EncodeResult<String> {
+    if group_separator == '-' {
+        return Err(EncodeError::AmbiguousGroupSeparator {
+            separator: group_separator,
+        });
+    }
+    if !is_valid_separator(group_separator) {
+        return Err(EncodeError::InvalidSeparator {
+            separator: group_separator,
+        });
+    }
+    if let Some(index) = field_sizes.iter().position(|&size| size == 0) {
+        return Err(EncodeError::EmptyField { index });
+    }
+    let total_size = field_sizes
+        .iter()
+        .fold(0_usize, |sum, &size| sum.saturating_add(size));
+    if total_size != data.len() {
+        return Err(EncodeError::FieldSizeMismatch {
+            expected: data.len(),
+            found: total_size,
+        });
+    }
+    if data.is_empty() {
+        return Ok(String::new());
+    }
+
+    let mut words = String::with_capacity(encoded_str_len(data.len()));
+    let mut remaining = data;
+    for &size in field_sizes {
+        let (field, rest) = remaining.split_at(size);
+        words.push_str(&bytes_to_pricklybird_string(field, '-'));
+        words.push(group_separator);
+        remaining = rest;
+    }
+    words.push_str(crc_word(data));
+    Ok(words)
+}
+
+/// Convert arbitrary data to a pricklybird string with CRC and append its ASCII bytes to `buf`.
+///
+/// Avoids allocating an intermediate `String` when the output is written into an existing buffer.
+/// Any buffer implementing `Extend<u8>` can be used, such as `Vec<u8>` or `bytes::BytesMut`.
+/// Empty input appends nothing.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_buf;
+/// let mut buf = b"code: ".to_vec();
+/// convert_to_pricklybird_buf(&[0x42, 0x43], &mut buf);
+/// assert_eq!(b"code: flea-flux-full", buf.as_slice());
+/// ```
+pub fn convert_to_pricklybird_buf<B: Extend<u8>>(data: &[u8], buf: &mut B) {
+    if data.is_empty() {
+        return;
+    }
+    let crc = calculate_crc8(data);
+    for (index, &byte) in data.iter().chain([crc].iter()).enumerate() {
+        if index != 0 {
+            buf.extend([b'-']);
+        }
+        buf.extend(BYTE_WORDLIST[byte as usize]);
+    }
+}
+
+/// Convert the concatenation of `slices` to a pricklybird string and attach CRC.
+///
+/// Useful for data scattered across multiple buffers, like a header and a body,
+/// since the slices are encoded in order without copying them into a single buffer first.
+/// The result is the same as `convert_to_pricklybird` of the concatenated data,
+/// so empty input, including only empty slices, results in an empty string.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_vectored;
+/// let header = [0x42_u8];
+/// let body = [0x43_u8];
+/// assert_eq!("flea-flux-full", convert_to_pricklybird_vectored(&[&header, &body]));
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird_vectored(slices: &[&[u8]]) -> String {
+    let total_len: usize = slices.iter().map(|slice| slice.len()).sum();
+    if total_len == 0 {
+        return String::new();
+    }
+    let mut crc = Crc8::new();
+    for slice in slices {
+        crc.update(slice);
+    }
+    let mut words = String::with_capacity(encoded_str_len(total_len));
+    let crc_byte = [crc.finalize()];
+    for (index, &byte) in slices
+        .iter()
+        .copied()
+        .flatten()
+        .chain(&crc_byte)
+        .enumerate()
+    {
+        if index != 0 {
+            words.push('-');
+        }
+        words.push_str(WORDLIST[byte as usize]);
+    }
+    words
+}
+
+/// Convert arbitrary data to a pricklybird string as ASCII bytes and attach CRC.
+///
+/// Returns the same characters as `convert_to_pricklybird` for sinks that expect bytes,
+/// such as byte buffers, without converting a `String`.
+/// The words and separators are ASCII, so the result is also valid UTF-8.
+/// Empty input results in an empty vector.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_ascii;
+/// assert_eq!(b"flea-flux-full".to_vec(), convert_to_pricklybird_ascii(&[0x42, 0x43]));
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird_ascii(data: &[u8]) -> Vec<u8> {
+    let mut code = Vec::with_capacity(encoded_str_len(data.len()));
+    convert_to_pricklybird_buf(data, &mut code);
+    code
+}
+
+/// Read all bytes from `input` and write them to `output` as a pricklybird string.
+///
+/// No newline is appended, so the output matches `convert_to_pricklybird` exactly.
+/// The input is read completely before writing, since the CRC covers all of it.
+/// `output` is flushed after writing.
+///
+/// # Errors
+/// Will return any error returned while reading from `input` or writing to `output`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::encode_stream;
+/// let mut output = Vec::new();
+/// encode_stream(&[0x42_u8, 0x43][..], &mut output).unwrap();
+/// assert_eq!(b"flea-flux-full", output.as_slice());
+/// ```
+pub fn encode_stream(
+    mut input: impl std::io::Read,
+    mut output: impl std::io::Write,
+) -> std::io::Result<()> {
+    let mut data = Vec::new();
+    let _ = input.read_to_end(&mut data)?;
+    output.write_all(&convert_to_pricklybird_ascii(&data))?;
+    output.flush()
+}
+
+/// Convert a pricklybird string to bytes and check CRC.
+///
+/// This function never panics, any malformed input results in an error.
+/// This is checked by the fuzz target in the `fuzz` directory of the repository.
+/// The length of the input is not limited,
+/// use `convert_from_pricklybird_limited` for untrusted input.
+///
+/// Surrounding whitespace is removed using `str::trim`, which includes Unicode whitespace
+/// such as non-breaking spaces. Between the words only the ASCII `-` is accepted,
+/// so any other character, like a non-breaking space next to a separator, is an error.
+/// Use `convert_from_pricklybird_flexible` to accept such input.
+///
+/// # Errors
+/// Will return `DecodeError::General` if:
+/// - The input is empty or a single word, since a payload word and the CRC word are required
+/// - The words in the input are not all four characters long
+/// - Words in the input dont appear in the wordlist
+///
+/// Will return `DecodeError::EmptyWord` if the input contains a leading,
+/// trailing or repeated separator.
+///
+/// Will return `DecodeError::NonAscii` if a word contains characters other than ASCII letters.
+///
+/// Will return `DecodeError::CRCError` if the CRC value does not match the input.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird;
+/// let code = "flea-flux-full";
+/// let data = convert_from_pricklybird(code).unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// ```
+pub fn convert_from_pricklybird(words: &str) -> Result<Vec<u8>> {
+    convert_from_pricklybird_opts(words, true)
+}
+
+/// Convert a pricklybird string given as raw bytes to bytes and check CRC.
+///
+/// Accepts input that has not been converted to a `str`,
+/// such as a byte slice received from the network or a `bytes::Bytes` buffer.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the input is not valid UTF-8,
+/// otherwise the same errors as `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_bytes;
+/// let data = convert_from_pricklybird_bytes(b"flea-flux-full\n").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// ```
+pub fn convert_from_pricklybird_bytes(words: &[u8]) -> Result<Vec<u8>> {
+    convert_from_pricklybird(words_from_utf8(words)?)
+}
+
+/// View the bytes of a pricklybird string read from a byte source as a string.
+///
+/// Used by the functions converting from bytes or streams, which report invalid UTF-8
+/// as a decoding error rather than an IO error.
+fn words_from_utf8(words: &[u8]) -> Result<&str> {
+    std::str::from_utf8(words).map_err(|_| DecodeError::General("Input is not valid UTF-8.".into()))
+}
+
+/// Read a pricklybird string from `input`, check CRC and write the decoded bytes to `output`.
+///
+/// Surrounding whitespace such as a trailing newline is accepted, like in `convert_from_pricklybird`.
+/// Nothing is written if the input can not be decoded.
+/// `output` is flushed after writing.
+///
+/// # Errors
+/// Will return `StreamError::Decode` if the input can not be decoded,
+/// see `convert_from_pricklybird_bytes`.
+///
+/// Will return `StreamError::Io` with any error returned while reading from `input`
+/// or writing to `output`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{DecodeError, StreamError, decode_stream};
+/// let mut output = Vec::new();
+/// decode_stream("flea-flux-full\n".as_bytes(), &mut output).unwrap();
+/// assert_eq!(vec![0x42, 0x43], output);
+///
+/// let error = decode_stream("flea-flux-flux".as_bytes(), Vec::new()).unwrap_err();
+/// assert!(matches!(error, StreamError::Decode(DecodeError::CRCError)));
+/// ```
+pub fn decode_stream(
+    mut input: impl std::io::Read,
+    mut output: impl std::io::Write,
+) -> StreamResult<()> {
+    let mut words = Vec::new();
+    let _ = input.read_to_end(&mut words)?;
+    let data = convert_from_pricklybird_bytes(&words)?;
+    output.write_all(&data)?;
+    output.flush()?;
+    Ok(())
+}
+
+/// Convert a pricklybird string to bytes and check CRC, rejecting inputs with more than `max_words` words.
+///
+/// The words are counted before decoding, so overly long inputs are rejected
+/// without allocating memory for them.
+/// `max_words` includes the CRC word.
+/// Prefer this function over `convert_from_pricklybird` for untrusted input,
+/// such as requests to a web service.
+///
+/// # Errors
+/// Will return `DecodeError::TooLong` if the input contains more than `max_words` words.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{DecodeError, convert_from_pricklybird_limited};
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_limited("flea-flux-full", 3).unwrap());
+/// assert_eq!(
+///     Err(DecodeError::TooLong { max: 2, found: 3 }),
+///     convert_from_pricklybird_limited("flea-flux-full", 2)
+/// );
+/// ```
+pub fn convert_from_pricklybird_limited(words: &str, max_words: usize) -> Result<Vec<u8>> {
+    let found = word_count(words);
+    if found > max_words {
+        return Err(DecodeError::TooLong {
+            max: max_words,
+            found,
+        });
+    }
+    convert_from_pricklybird(words)
+}
+
+/// Convert a pricklybird string to bytes and check CRC, rejecting payloads shorter than `min_payload_words`.
+///
+/// Enforces a minimum payload size required by a protocol while decoding,
+/// `min_payload_words` excludes the CRC word.
+/// `convert_from_pricklybird` itself requires a single payload word.
+///
+/// # Errors
+/// Will return `DecodeError::TooShort` if the payload has fewer than `min_payload_words` words.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise,
+/// these take precedence since the input is decoded before its length is checked.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{DecodeError, convert_from_pricklybird_min};
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_min("flea-flux-full", 2).unwrap());
+/// assert_eq!(
+///     Err(DecodeError::TooShort { min: 4, found: 2 }),
+///     convert_from_pricklybird_min("flea-flux-full", 4)
+/// );
+/// ```
+pub fn convert_from_pricklybird_min(words: &str, min_payload_words: usize) -> Result<Vec<u8>> {
+    let data = convert_from_pricklybird(words)?;
+    if data.len() < min_payload_words {
+        return Err(DecodeError::TooShort {
+            min: min_payload_words,
+            found: data.len(),
+        });
+    }
+    Ok(data)
+}
+
+/// Convert a pricklybird string to bytes, optionally skipping the CRC check.
+///
+/// With `verify_crc` set to `true` this behaves exactly like `convert_from_pricklybird`.
+/// With `verify_crc` set to `false` the last word is still treated as the CRC
+/// and discarded, but its value is not checked.
+/// This is useful to inspect codes with a deliberately wrong CRC, such as test fixtures.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`,
+/// except for `DecodeError::CRCError` when `verify_crc` is `false`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_opts;
+/// assert_eq!(vec![0x43, 0x42], convert_from_pricklybird_opts("flux-flea-full", false).unwrap());
+/// assert!(convert_from_pricklybird_opts("flux-flea-full", true).is_err());
+/// ```
+pub fn convert_from_pricklybird_opts(words: &str, verify_crc: bool) -> Result<Vec<u8>> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    if verify_crc {
+        decode_with_crc(&word_vec)
+    } else {
+        Ok(decode_checked(&word_vec)?.payload)
+    }
+}
+
+/// Convert a pricklybird string to bytes, reporting both the expected and the found CRC.
+///
+/// Like `convert_from_pricklybird_checked` a CRC mismatch is never an error,
+/// the payload is returned so it can be corrected using external information.
+/// Additionally both CRC values are returned, for example for logging on noisy channels.
+/// Structural errors such as invalid words are still returned as errors.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`, except for `DecodeError::CRCError`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_tolerant;
+/// let result = convert_from_pricklybird_tolerant("flux-flea-full").unwrap();
+/// assert_eq!(vec![0x43, 0x42], result.payload);
+/// assert!(!result.crc_ok);
+/// assert_ne!(result.expected_crc, result.found_crc);
+/// ```
+pub fn convert_from_pricklybird_tolerant(words: &str) -> Result<TolerantDecodeResult> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    check_word_count(separated_word_count(&word_vec))?;
+
+    let mut payload = words_to_bytes(&word_vec)?;
+    // At least two words are present, so the CRC word exists.
+    let found_crc = payload.pop().unwrap_or_default();
+    let expected_crc = calculate_crc8(&payload);
+    Ok(TolerantDecodeResult {
+        payload,
+        crc_ok: expected_crc == found_crc,
+        expected_crc,
+        found_crc,
+    })
+}
+
+/// Convert a pricklybird string that may lack the CRC word to bytes.
+///
+/// If the last word is a valid CRC of the preceding words it is removed,
+/// otherwise all words are treated as payload, which allows decoding legacy codes without CRC.
+/// Returns the payload and whether a valid CRC was found and removed.
+///
+/// This is inherently ambiguous: in about one of 256 codes without CRC,
+/// the last payload byte happens to equal the CRC of the preceding bytes
+/// and is wrongly removed. Likewise a code with CRC that contains an error is
+/// returned in full instead of being rejected, so errors in such codes are not detected.
+/// Only use this for corpora that actually mix both formats.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the input is empty.
+///
+/// Will return the same errors as `convert_from_pricklybird` for invalid words.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_optional_crc;
+/// let (data, crc_found) = convert_from_pricklybird_optional_crc("flea-flux-full").unwrap();
+/// assert_eq!((vec![0x42, 0x43], true), (data, crc_found));
+/// let (data, crc_found) = convert_from_pricklybird_optional_crc("flea-flux").unwrap();
+/// assert_eq!((vec![0x42, 0x43], false), (data, crc_found));
+/// ```
+pub fn convert_from_pricklybird_optional_crc(words: &str) -> Result<(Vec<u8>, bool)> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    if separated_word_count(&word_vec) == 0 {
+        return Err(DecodeError::General("Input is empty.".into()));
+    }
+
+    let mut payload = words_to_bytes(&word_vec)?;
+    // A single word can not contain both payload and CRC.
+    let crc_found = payload.len() > 1 && calculate_crc8(&payload) == 0;
+    if crc_found {
+        // Remove CRC
+        let _ = payload.pop();
+    }
+    Ok((payload, crc_found))
+}
+
+/// Convert a pricklybird string that may use arbitrary separators to bytes and check CRC.
+///
+/// Any run of characters that are neither letters nor digits is treated as a separator,
+/// so codes like `flea flux·full` or `flea—flux-full` are accepted.
+/// This includes Unicode spaces such as the non-breaking space `U+00A0`, the thin space `U+2009`
+/// and zero width characters, which are commonly introduced by word processors and web pages,
+/// so they are handled like regular spaces anywhere in the input.
+/// Every remaining token must be exactly four ASCII letters.
+/// Use `convert_from_pricklybird` if only the standard `-` separator should be accepted.
+///
+/// # Errors
+/// Will return `DecodeError::General` if:
+/// - The input is empty or a single word, since a payload word and the CRC word are required
+/// - The tokens in the input are not all four characters long
+/// - Words in the input dont appear in the wordlist
+///
+/// Will return `DecodeError::NonAscii` if a token contains characters
+/// other than ASCII letters, e.g. `flea1`.
+///
+/// Will return `DecodeError::CRCError` if the CRC value does not match the input.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_flexible;
+/// let data = convert_from_pricklybird_flexible("flea flux\u{b7}full").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// let data = convert_from_pricklybird_flexible("flea\u{2014}flux-full").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// ```
+pub fn convert_from_pricklybird_flexible(words: &str) -> Result<Vec<u8>> {
+    let word_vec: Vec<&str> = words
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    // The separator rules above let letters and digits from any script through,
+    // these are rejected by the ASCII check in `words_to_bytes`.
+    decode_with_crc(&word_vec)
+}
+
+/// Split a pricklybird string into its word tokens without allocating.
+///
+/// Surrounding whitespace is trimmed and the rest is split at every `-`,
+/// exactly like `convert_from_pricklybird` does before decoding.
+/// Tokens are not validated, so repeated separators yield empty tokens,
+/// and input that is empty after trimming yields a single empty token.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::tokens;
+/// assert_eq!(vec!["flea", "FLUX", "full"], tokens(" flea-FLUX-full\n").collect::<Vec<_>>());
+/// assert_eq!(vec!["flea", "", "full"], tokens("flea--full").collect::<Vec<_>>());
+/// assert_eq!(3, tokens("flea-flux-full").count());
+/// ```
+pub fn tokens(words: &str) -> impl Iterator<Item = &str> {
+    words.trim().split('-')
+}
+
+/// Convert already separated pricklybird words to bytes and check CRC.
+///
+/// Intended for callers that split the input using their own rules,
+/// avoiding joining the tokens into a string for `convert_from_pricklybird`.
+/// The last token is the CRC word. Tokens are not trimmed,
+/// so whitespace has to be handled when splitting.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`,
+/// with `DecodeError::EmptyWord` returned for empty tokens.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decode_tokens;
+/// let data = decode_tokens("flea flux  full".split_whitespace()).unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// ```
+pub fn decode_tokens<'a, I: IntoIterator<Item = &'a str>>(tokens: I) -> Result<Vec<u8>> {
+    let word_vec: Vec<&str> = tokens.into_iter().collect();
+    decode_with_crc(&word_vec)
+}
+
+/// Lazily decode each word of a pricklybird string, yielding its position and byte value.
+///
+/// Words are split using `tokens` and validated like in `words_to_bytes`,
+/// an invalid word yields an error at its position and decoding continues with the next word.
+/// The last word is the CRC word and is yielded like any other word,
+/// checking the CRC is left to the caller, for example using `Crc8`.
+/// Empty input yields a single `DecodeError::EmptyWord` at position zero.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{Crc8, decode_positioned};
+/// let mut crc = Crc8::new();
+/// let mut positions = Vec::new();
+/// for (position, result) in decode_positioned("flea-flux-full") {
+///     positions.push(position);
+///     crc.update(&[result.unwrap()]);
+/// }
+/// assert_eq!(vec![0, 1, 2], positions);
+/// assert_eq!(0, crc.finalize());
+/// ```
+pub fn decode_positioned(words: &str) -> impl Iterator<Item = (usize, Result<u8>)> {
+    tokens(words)
+        .enumerate()
+        .map(|(index, word)| (index, decode_word(word, index)))
+}
+
+/// Decode every word of a pricklybird string, returning one result per word.
+///
+/// Never stops at the first error, so all problems can be reported at once,
+/// for example in a validation report or user interface.
+/// Words are split and validated like in `decode_positioned`, the result for a word
+/// is at its position in the returned vector and errors contain the word index.
+/// The last result belongs to the CRC word, checking the CRC is left to the caller.
+/// Empty input results in a single `DecodeError::EmptyWord` at position zero.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{DecodeError, validate_all};
+/// let results = validate_all("flea--full");
+/// assert_eq!(Ok(0x42), results[0]);
+/// assert_eq!(Err(DecodeError::EmptyWord { index: 1 }), results[1]);
+/// assert_eq!(Ok(0x46), results[2]);
+/// ```
+#[must_use]
+pub fn validate_all(words: &str) -> Vec<Result<u8>> {
+    decode_positioned(words).map(|(_, result)| result).collect()
+}
+
+/// Convert a pricklybird string to bytes written into `out` and check CRC.
+///
+/// Returns the number of payload bytes written to the start of `out`.
+/// Unlike `convert_from_pricklybird` no memory is allocated when decoding succeeds,
+/// making this suitable for targets without an allocator.
+/// If an error occurs `out` may contain partially decoded data.
+///
+/// # Errors
+/// Will return `DecodeError::BufferTooSmall` if `out` can not hold the payload,
+/// this is checked before decoding any words.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decode_into_slice;
+/// let mut buffer = [0_u8; 8];
+/// let written = decode_into_slice("flea-flux-full", &mut buffer).unwrap();
+/// assert_eq!(&[0x42, 0x43], &buffer[..written]);
+/// ```
+pub fn decode_into_slice(words: &str, out: &mut [u8]) -> Result<usize> {
+    let trimmed = words.trim();
+    let total_words = word_count(trimmed);
+    check_word_count(total_words)?;
+    let needed = total_words - 1;
+    if out.len() < needed {
+        return Err(DecodeError::BufferTooSmall { needed });
+    }
+
+    let remainder = decode_words_with_crc(trimmed, |index, byte| {
+        // The last word is the CRC and not part of the payload.
+        if index < needed {
+            out[index] = byte;
+        }
+    })?;
+    if remainder != 0 {
+        return Err(DecodeError::CRCError);
+    }
+    Ok(needed)
+}
+
+/// Decode each word of a trimmed pricklybird string and pass its index and byte value to `handle`.
+///
+/// Returns the CRC remainder over all words, including the CRC word, which is zero for valid codes.
+/// The number of words is not checked. No memory is allocated.
+fn decode_words_with_crc(trimmed: &str, mut handle: impl FnMut(usize, u8)) -> Result<u8> {
+    let mut crc = Crc8::new();
+    for (index, word) in tokens(trimmed).enumerate() {
+        let byte = decode_word(word, index)?;
+        crc.update(&[byte]);
+        handle(index, byte);
+    }
+    Ok(crc.finalize())
+}
+
+/// Check that a pricklybird string is well formed and its CRC is valid, without decoding it.
+///
+/// No memory is allocated, the payload is discarded while the CRC is calculated.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{DecodeError, verify_pricklybird};
+/// assert_eq!(Ok(()), verify_pricklybird("flea-flux-full"));
+/// assert_eq!(Err(DecodeError::CRCError), verify_pricklybird("flux-flea-full"));
+/// ```
+pub fn verify_pricklybird(words: &str) -> Result<()> {
+    if crc_of_code(words)? != 0 {
+        return Err(DecodeError::CRCError);
+    }
+    Ok(())
+}
+
+/// Calculate the CRC-8 over all words of a pricklybird string, including the CRC word.
+///
+/// The result is the CRC remainder, which is zero for valid codes.
+/// Lower level than `verify_pricklybird`, for custom validation logic.
+/// No memory is allocated.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`, except for `DecodeError::CRCError`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::crc_of_code;
+/// assert_eq!(Ok(0), crc_of_code("flea-flux-full"));
+/// assert_ne!(Ok(0), crc_of_code("flux-flea-full"));
+/// ```
+pub fn crc_of_code(words: &str) -> Result<u8> {
+    let trimmed = words.trim();
+    check_word_count(word_count(trimmed))?;
+    decode_words_with_crc(trimmed, |_, _| {})
+}
+
+/// Find the byte offset of the first character at which the words of a pricklybird string are invalid.
+///
+/// Checks the words using the same rules as `convert_from_pricklybird` and returns
+/// the offset into `words`, including any leading whitespace, of:
+/// - The first character that is not an ASCII letter
+/// - The fifth letter of a word that is too long
+/// - The end of a word that is too short, or the position of an empty word
+/// - The start of a word that does not appear in the wordlist
+///
+/// Returns `None` if all words are valid. Neither the number of words nor the CRC is checked,
+/// so any prefix of a valid code that ends in a complete word returns `None`.
+/// Intended for highlighting errors while a code is typed.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::first_error_position;
+/// assert_eq!(None, first_error_position("flea-flux-full"));
+/// assert_eq!(Some(7), first_error_position("flea-fl4x-full"));
+/// assert_eq!(Some(8), first_error_position("flea-flu-full"));
+/// assert_eq!(Some(5), first_error_position("flea-flax-full"));
+/// ```
+#[must_use]
+pub fn first_error_position(words: &str) -> Option<usize> {
+    let mut word_start = words.len() - words.trim_start().len();
+    for word in tokens(words) {
+        let word_bytes = word.as_bytes();
+        if let Some(letter_index) = word_bytes
+            .iter()
+            .position(|letter| !letter.is_ascii_alphabetic())
+        {
+            return Some(word_start + letter_index);
+        }
+        if word_bytes.len() != 4 {
+            return Some(word_start + word_bytes.len().min(4));
+        }
+        if word_to_byte(word).is_none() {
+            return Some(word_start);
+        }
+        // Skip the word and its separator.
+        word_start += word.len() + 1;
+    }
+    None
+}
+
+/// Check whether the input currently forms a complete pricklybird string with a valid CRC.
+///
+/// Intended for user interfaces that indicate validity while the code is typed or received,
+/// each prefix of a valid code is very likely to be reported as incomplete
+/// until the CRC word has been entered.
+/// Since any word can be a CRC word, a prefix occasionally forms a valid code by chance.
+/// Uses `verify_pricklybird`, so no memory is allocated.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::is_complete;
+/// assert!(!is_complete("flea-flux"));
+/// assert!(!is_complete("flea-flux-fu"));
+/// assert!(is_complete("flea-flux-full"));
+/// ```
+#[must_use]
+pub fn is_complete(words: &str) -> bool {
+    verify_pricklybird(words).is_ok()
+}
+
+/// Convert a possibly messy pricklybird string into its canonical form.
+///
+/// Accepts the same input as `convert_from_pricklybird_flexible`,
+/// so uppercase letters, surrounding whitespace and alternative separators are tolerated.
+/// The result is lowercase, separated by `-` and has a valid CRC,
+/// making it suitable for storage and comparison.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird_flexible`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::normalize;
+/// assert_eq!("flea-flux-full", normalize(" FLEA flux\u{b7}Full\n").unwrap());
+/// ```
+pub fn normalize(words: &str) -> Result<String> {
+    let data = convert_from_pricklybird_flexible(words)?;
+    Ok(convert_to_pricklybird(data))
+}
+
+/// Check whether two pricklybird strings encode the same payload, ignoring their formatting.
+///
+/// Both strings are decoded using `convert_from_pricklybird_flexible`,
+/// so differences in case, surrounding whitespace and separators do not matter.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird_flexible`
+/// if either string fails to decode.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::codes_equal;
+/// assert!(codes_equal("flea-flux-full", " FLEA Flux-full\n").unwrap());
+/// assert!(!codes_equal("flea-flux-full", "flux-flea-call").unwrap());
+/// assert!(codes_equal("flea-flux-full", "flux-flea-full").is_err());
+/// ```
+pub fn codes_equal(a: &str, b: &str) -> Result<bool> {
+    Ok(convert_from_pricklybird_flexible(a)? == convert_from_pricklybird_flexible(b)?)
+}
+
+/// Test vectors of the pricklybird specification as label and payload,
+/// matching `tests/vectors.txt`.
+const KNOWN_VECTORS: [(&str, &[u8]); 5] = [
+    ("DEADBEEF example", &[0xDE, 0xAD, 0xBE, 0xEF]),
+    ("4243 example", &[0x42, 0x43]),
+    ("1234567890 example", &[0x12, 0x34, 0x56, 0x78, 0x90]),
+    ("five zero bytes", &[0x00; 5]),
+    ("five 0xFF bytes", &[0xFF; 5]),
+];
+
+/// Identify the specification test vector a pricklybird string encodes, for diagnostics.
+///
+/// Returns a label describing the test vector, or `None` if the payload is not a
+/// known test vector or the input can not be decoded.
+/// The input is decoded using `convert_from_pricklybird`,
+/// so uppercase words and surrounding whitespace still match.
+/// Useful to confirm that other tooling produces canonical output for known inputs.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::identify_known_vector;
+/// assert_eq!(Some("DEADBEEF example"), identify_known_vector("turf-port-rust-warn-void"));
+/// assert_eq!(None, identify_known_vector("flux-flea-call"));
+/// ```
+#[must_use]
+pub fn identify_known_vector(words: &str) -> Option<&'static str> {
+    let data = convert_from_pricklybird(words).ok()?;
+    KNOWN_VECTORS
+        .iter()
+        .find(|(_, payload)| *payload == data.as_slice())
+        .map(|&(label, _)| label)
+}
+
+/// Convert a pricklybird string to bytes and report whether the CRC is valid.
+///
+/// Unlike `convert_from_pricklybird` a CRC mismatch is not treated as an error.
+/// Instead the payload is returned with `crc_ok` set to `false`,
+/// allowing corrupt but parseable data to be inspected.
+///
+/// # Errors
+/// Will return `DecodeError::General` if:
+/// - The input is empty or a single word, since a payload word and the CRC word are required
+/// - The words in the input are not all four characters long
+/// - Words in the input dont appear in the wordlist
+///
+/// Will return `DecodeError::EmptyWord` if the input contains a leading,
+/// trailing or repeated separator.
+///
+/// Will return `DecodeError::NonAscii` if a word contains characters other than ASCII letters.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_checked;
+/// let result = convert_from_pricklybird_checked("flea-flux-full").unwrap();
+/// assert_eq!(vec![0x42, 0x43], result.payload);
+/// assert!(result.crc_ok);
+/// let result = convert_from_pricklybird_checked("flux-flea-full").unwrap();
+/// assert_eq!(vec![0x43, 0x42], result.payload);
+/// assert!(!result.crc_ok);
+/// ```
+pub fn convert_from_pricklybird_checked(words: &str) -> Result<DecodeResult> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    decode_checked(&word_vec)
+}
+
+/// Convert a pricklybird string to the byte of each word and check CRC.
+///
+/// Unlike `convert_from_pricklybird` the mapping of every word to its byte is kept,
+/// including the CRC word, which is flagged using `is_crc`.
+/// Useful to show how a pricklybird string maps to bytes, for example in tutorials.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decode_verbose;
+/// let decoded = decode_verbose("flea-FLUX-full").unwrap();
+/// assert_eq!(("flux", 0x43, false), (decoded[1].word, decoded[1].byte, decoded[1].is_crc));
+/// assert_eq!(("full", true), (decoded[2].word, decoded[2].is_crc));
+/// ```
+pub fn decode_verbose(words: &str) -> Result<Vec<DecodedWord>> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    check_word_count(separated_word_count(&word_vec))?;
+
+    let bytes = words_to_bytes(&word_vec)?;
+    if calculate_crc8(&bytes) != 0 {
+        return Err(DecodeError::CRCError);
+    }
+    let crc_index = bytes.len() - 1;
+    Ok(bytes
+        .into_iter()
+        .enumerate()
+        .map(|(index, byte)| DecodedWord {
+            word: WORDLIST[byte as usize],
+            byte,
+            is_crc: index == crc_index,
+        })
+        .collect())
+}
+
+/// Convert arbitrary data to a pricklybird string prefixed with the specification version.
+///
+/// The output consists of `PRICKLYBIRD_VERSION`, a `:` and the result of `convert_to_pricklybird`.
+/// This is an extension to the specification, use `convert_to_pricklybird`
+/// for spec compliant output.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_versioned;
+/// let code = convert_to_pricklybird_versioned(&[0x42, 0x43]);
+/// assert_eq!("v1:flea-flux-full", code);
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird_versioned(data: &[u8]) -> String {
+    format!(
+        "{PRICKLYBIRD_VERSION}{VERSION_SEPARATOR}{}",
+        convert_to_pricklybird(data)
+    )
+}
+
+/// Convert a version prefixed pricklybird string to bytes and check version and CRC.
+///
+/// Expects input in the format produced by `convert_to_pricklybird_versioned`.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the input has no version prefix,
+/// or for any of the reasons listed for `convert_from_pricklybird`.
+///
+/// Will return `DecodeError::VersionMismatch` if the version prefix
+/// does not match `PRICKLYBIRD_VERSION`.
+///
+/// Will return `DecodeError::CRCError` if the CRC value does not match the input.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{DecodeError, convert_from_pricklybird_versioned};
+/// let data = convert_from_pricklybird_versioned("v1:flea-flux-full").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// assert!(matches!(
+///     convert_from_pricklybird_versioned("v2:flea-flux-full"),
+///     Err(DecodeError::VersionMismatch { .. })
+/// ));
+/// ```
+pub fn convert_from_pricklybird_versioned(words: &str) -> Result<Vec<u8>> {
+    let Some((version, code)) = words.trim().split_once(VERSION_SEPARATOR) else {
+        return Err(DecodeError::General(
+            "Input is missing the version prefix.".into(),
+        ));
+    };
+    if version != PRICKLYBIRD_VERSION {
+        return Err(DecodeError::VersionMismatch {
+            found: version.to_owned(),
+            expected: PRICKLYBIRD_VERSION,
+        });
+    }
+    convert_from_pricklybird(code)
+}
+
+/// Convert a pricklybird string with or without version prefix to bytes and check CRC.
+///
+/// Accepts both the output of `convert_to_pricklybird_versioned` and of `convert_to_pricklybird`,
+/// easing migration between the two formats.
+/// If the input contains the version separator `:` the prefix is validated as in
+/// `convert_from_pricklybird_versioned`, otherwise the input is decoded as a bare code.
+/// Use `convert_from_pricklybird_versioned` or `convert_from_pricklybird`
+/// to require or reject the prefix.
+///
+/// # Errors
+/// Will return `DecodeError::VersionMismatch` if a version prefix is present
+/// but does not match `PRICKLYBIRD_VERSION`.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{DecodeError, convert_from_pricklybird_any};
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_any("v1:flea-flux-full").unwrap());
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_any("flea-flux-full").unwrap());
+/// assert!(matches!(
+///     convert_from_pricklybird_any("v2:flea-flux-full"),
+///     Err(DecodeError::VersionMismatch { .. })
+/// ));
+/// ```
+pub fn convert_from_pricklybird_any(words: &str) -> Result<Vec<u8>> {
+    if words.contains(VERSION_SEPARATOR) {
+        convert_from_pricklybird_versioned(words)
+    } else {
+        convert_from_pricklybird(words)
+    }
+}
+
+/// Convert arbitrary data to a pricklybird string for use in URLs and attach CRC.
+///
+/// Words are separated by `_` instead of `-`.
+/// Both are unreserved characters in URLs, but `_` is treated as part of a word by most
+/// text selection, so the whole code is selected by double clicking and copied in one piece.
+/// Use `convert_from_pricklybird_url` to decode the result.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_url;
+/// assert_eq!("flea_flux_full", convert_to_pricklybird_url(&[0x42, 0x43]));
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird_url(data: &[u8]) -> String {
+    EncodeOptions::new()
+        .separator(URL_SEPARATOR)
+        .encode_words(data)
+}
+
+/// Convert a pricklybird string created by `convert_to_pricklybird_url` to bytes and check CRC.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_url;
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_url("flea_flux_full").unwrap());
+/// ```
+pub fn convert_from_pricklybird_url(words: &str) -> Result<Vec<u8>> {
+    DecodeOptions::new().separator(URL_SEPARATOR).decode(words)
+}
+
+/// Convert arbitrary data to a pricklybird string for QR codes and attach CRC.
+///
+/// The words are uppercase and separated by spaces, like `FLEA FLUX FULL`.
+/// QR codes encode text consisting only of digits, uppercase letters, spaces and `$%*+-./:`
+/// in alphanumeric mode using 5.5 bits per character, instead of 8 bits per character in byte mode.
+/// The lowercase output of `convert_to_pricklybird` requires byte mode,
+/// so this form needs about 31 percent fewer data bits and fits into smaller QR codes.
+/// Use `decode_qr_alphanumeric_code` to decode the result.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::qr_alphanumeric_code;
+/// assert_eq!("FLEA FLUX FULL", qr_alphanumeric_code(&[0x42, 0x43]));
+/// ```
+#[must_use]
+pub fn qr_alphanumeric_code(data: &[u8]) -> String {
+    EncodeOptions::new()
+        .separator(QR_SEPARATOR)
+        .case(WordCase::Upper)
+        .encode_words(data)
+}
+
+/// Convert a pricklybird string created by `qr_alphanumeric_code` to bytes and check CRC.
+///
+/// The conversion is case insensitive like `convert_from_pricklybird`,
+/// but the words must be separated by single spaces.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decode_qr_alphanumeric_code;
+/// assert_eq!(vec![0x42, 0x43], decode_qr_alphanumeric_code("FLEA FLUX FULL").unwrap());
+/// ```
+pub fn decode_qr_alphanumeric_code(words: &str) -> Result<Vec<u8>> {
+    DecodeOptions::new().separator(QR_SEPARATOR).decode(words)
+}
+
+/// Separates the words in strings created by `phonetic_readout`.
+const READOUT_SEPARATOR: &str = ", ";
+
+/// Convert a pricklybird string to a form suited for reading it aloud, after checking CRC.
+///
+/// The words are separated by a comma and a space, like `flea, flux, full`,
+/// so text to speech software and people dictating a code pause between words.
+/// The input is decoded first, so only valid codes are read out
+/// and messy input, like mixed case or surrounding whitespace, is output in canonical form.
+/// Use `WordCase::Upper` to make the words easier to read on screen.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{WordCase, phonetic_readout};
+/// assert_eq!("flea, flux, full", phonetic_readout(" FLEA-flux-full", WordCase::Lower).unwrap());
+/// assert_eq!("FLEA, FLUX, FULL", phonetic_readout("flea-flux-full", WordCase::Upper).unwrap());
+/// ```
+pub fn phonetic_readout(words: &str, case: WordCase) -> Result<String> {
+    let data = convert_from_pricklybird(words)?;
+    let code = EncodeOptions::new().case(case).encode_words(&data);
+    Ok(code.replace('-', READOUT_SEPARATOR))
+}
+
+/// Convert arbitrary data to a pricklybird string prefixed with a human-readable label.
+///
+/// The label is separated from the words by a `-`, like `BACKUP-flea-flux-full`.
+/// It is not covered by the CRC, use `convert_from_pricklybird_labeled` with the same label to decode.
+/// Since the decoder is given the expected label, labels may contain dashes themselves.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_labeled;
+/// let code = convert_to_pricklybird_labeled(&[0x42, 0x43], "BACKUP");
+/// assert_eq!("BACKUP-flea-flux-full", code);
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird_labeled(data: &[u8], label: &str) -> String {
+    format!("{label}-{}", convert_to_pricklybird(data))
+}
+
+/// Convert a pricklybird string prefixed with `label` to bytes and check CRC.
+///
+/// Expects input in the layout produced by `convert_to_pricklybird_labeled`.
+/// The label is compared ignoring ASCII case, like the words.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the input does not start with `label` followed by a `-`.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_labeled;
+/// let data = convert_from_pricklybird_labeled("backup-flea-flux-full", "BACKUP").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// assert!(convert_from_pricklybird_labeled("flea-flux-full", "BACKUP").is_err());
+/// ```
+pub fn convert_from_pricklybird_labeled(words: &str, label: &str) -> Result<Vec<u8>> {
+    let input = words.trim_start();
+    let unlabeled = input
+        .get(..label.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(label))
+        .and_then(|_| input[label.len()..].strip_prefix('-'))
+        .ok_or_else(|| {
+            DecodeError::General("Input does not start with the expected label.".into())
+        })?;
+    convert_from_pricklybird(unlabeled)
+}
+
+/// Get the pricklybird word for the CRC of `data`.
+///
+/// This is the word `convert_to_pricklybird` appends to the payload,
+/// useful when building custom layouts such as `convert_to_pricklybird_crc_first`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::crc_word;
+/// assert_eq!("full", crc_word(&[0x42, 0x43]));
+/// ```
+#[must_use]
+pub fn crc_word(data: &[u8]) -> &'static str {
+    byte_to_word(calculate_crc8(data))
+}
+
+/// Replace the CRC word of a pricklybird string with the correct CRC of its payload words.
+///
+/// The payload words are authoritative and the last word is discarded as a stale CRC,
+/// even if it is correct, so the result is always a valid code for the payload.
+/// Useful in editing tools after payload words were changed.
+/// The result is in canonical lowercase form.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`, except for `DecodeError::CRCError`.
+/// The discarded last word must still be a valid word.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::refresh_crc;
+/// // The payload was edited from "flea-flux" to "flux-flea", leaving the CRC word stale.
+/// assert_eq!("flux-flea-call", refresh_crc("flux-flea-full").unwrap());
+/// ```
+pub fn refresh_crc(words: &str) -> Result<String> {
+    let payload = convert_from_pricklybird_opts(words, false)?;
+    Ok(convert_to_pricklybird(payload))
+}
+
+/// Convert arbitrary data to a pricklybird string with the CRC word in front.
+///
+/// This is a variant of the layout defined by the specification,
+/// which places the CRC word after the payload.
+/// Placing it in front allows receivers to reject codes early.
+/// The CRC is calculated over the payload only, just like in `convert_to_pricklybird`.
+/// Use `convert_from_pricklybird_crc_first` to decode the result.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_to_pricklybird_crc_first;
+/// let code = convert_to_pricklybird_crc_first(&[0x42, 0x43]);
+/// assert_eq!("full-flea-flux", code);
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird_crc_first(data: &[u8]) -> String {
+    EncodeOptions::new()
+        .crc_position(CrcPosition::First)
+        .encode_words(data)
+}
+
+/// Convert a pricklybird string with the CRC word in front to bytes and check CRC.
+///
+/// Expects input in the layout produced by `convert_to_pricklybird_crc_first`.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_crc_first;
+/// let data = convert_from_pricklybird_crc_first("full-flea-flux").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// ```
+pub fn convert_from_pricklybird_crc_first(words: &str) -> Result<Vec<u8>> {
+    DecodeOptions::new()
+        .crc_position(CrcPosition::First)
+        .decode(words)
+}
+
+/// Convert arbitrary data to a pricklybird string with a CRC that also depends on `salt`.
+///
+/// The CRC is calculated over `salt` followed by `data`, but only `data` and the CRC are encoded.
+/// Codes created with different salts, e.g. for different applications,
+/// therefore most likely fail the CRC check when decoded with the wrong salt.
+/// Use `convert_from_pricklybird_salted` with the same salt to decode the result.
+///
+/// This is only domain separation, not a cryptographic protection:
+/// anyone can compute the CRC for any salt, and one in 256 codes still validates under a wrong salt.
+/// Since the CRC starts at zero, leading zero bytes of the salt have no effect,
+/// so a salt consisting only of zero bytes is the same as no salt.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{convert_from_pricklybird_salted, convert_to_pricklybird_salted};
+/// let code = convert_to_pricklybird_salted(&[0x42, 0x43], b"invoices");
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_salted(&code, b"invoices").unwrap());
+/// assert!(convert_from_pricklybird_salted(&code, b"receipts").is_err());
+/// ```
+#[must_use]
+pub fn convert_to_pricklybird_salted(data: &[u8], salt: &[u8]) -> String {
+    if data.is_empty() {
+        return String::new();
+    }
+    let mut crc = Crc8::new();
+    crc.update(salt);
+    crc.update(data);
+    let mut data_with_crc = Vec::with_capacity(data.len() + 1);
+    data_with_crc.extend_from_slice(data);
+    data_with_crc.push(crc.finalize());
+    join_words(&data_with_crc)
+}
+
+/// Convert a pricklybird string created by `convert_to_pricklybird_salted` to bytes and check the salted CRC.
+///
+/// # Errors
+/// Will return the same errors as `convert_from_pricklybird`,
+/// `DecodeError::CRCError` is also returned if `salt` differs from the one used for encoding.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_salted;
+/// let code = "flea-flux-full";
+/// // Unsalted codes decode with an empty salt.
+/// assert_eq!(vec![0x42, 0x43], convert_from_pricklybird_salted(code, &[]).unwrap());
+/// ```
+pub fn convert_from_pricklybird_salted(words: &str, salt: &[u8]) -> Result<Vec<u8>> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    check_word_count(separated_word_count(&word_vec))?;
+
+    let mut payload = words_to_bytes(&word_vec)?;
+    let mut crc = Crc8::new();
+    crc.update(salt);
+    crc.update(&payload);
+    if crc.finalize() != 0 {
+        return Err(DecodeError::CRCError);
+    }
+    // Remove CRC
+    let _ = payload.pop();
+    Ok(payload)
+}
+
+/// Convert a `u16` to a pricklybird string and attach CRC.
+///
+/// The value is encoded as its fixed width two byte big-endian representation,
+/// so every value results in three words. Use `decode_u16` to decode the result.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::encode_u16;
+/// assert_eq!("flea-flux-full", encode_u16(0x4243));
+/// ```
+#[must_use]
+pub fn encode_u16(value: u16) -> String {
+    convert_to_pricklybird(value.to_be_bytes())
+}
+
+/// Convert a `u32` to a pricklybird string and attach CRC.
+///
+/// The value is encoded as its fixed width four byte big-endian representation,
+/// so every value results in five words. Use `decode_u32` to decode the result.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::encode_u32;
+/// assert_eq!("turf-port-rust-warn-void", encode_u32(0xDEAD_BEEF));
+/// ```
+#[must_use]
+pub fn encode_u32(value: u32) -> String {
+    convert_to_pricklybird(value.to_be_bytes())
+}
+
+/// Convert a `u64` to a pricklybird string and attach CRC.
+///
+/// The value is encoded as its fixed width eight byte big-endian representation,
+/// so every value results in nine words. Use `decode_u64` to decode the result.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{decode_u64, encode_u64};
+/// let code = encode_u64(42);
+/// assert_eq!(9, code.split('-').count());
+/// assert_eq!(42, decode_u64(&code).unwrap());
+/// ```
+#[must_use]
+pub fn encode_u64(value: u64) -> String {
+    convert_to_pricklybird(value.to_be_bytes())
+}
+
+/// Convert a pricklybird string created by `encode_u16` back to the integer and check CRC.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the payload is not exactly two bytes long.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decode_u16;
+/// assert_eq!(0x4243, decode_u16("flea-flux-full").unwrap());
+/// assert!(decode_u16("flea-deer").is_err());
+/// ```
+pub fn decode_u16(words: &str) -> Result<u16> {
+    decode_fixed_width(words).map(u16::from_be_bytes)
+}
+
+/// Convert a pricklybird string created by `encode_u32` back to the integer and check CRC.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the payload is not exactly four bytes long.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decode_u32;
+/// assert_eq!(0xDEAD_BEEF, decode_u32("turf-port-rust-warn-void").unwrap());
+/// ```
+pub fn decode_u32(words: &str) -> Result<u32> {
+    decode_fixed_width(words).map(u32::from_be_bytes)
+}
+
+/// Convert a pricklybird string created by `encode_u64` back to the integer and check CRC.
+///
+/// # Errors
+/// Will return `DecodeError::General` if the payload is not exactly eight bytes long.
+///
+/// Will return the same errors as `convert_from_pricklybird` otherwise.
+pub fn decode_u64(words: &str) -> Result<u64> {
+    decode_fixed_width(words).map(u64::from_be_bytes)
+}
+
+/// Convert a pricklybird string to a payload of exactly `N` bytes and check CRC.
+fn decode_fixed_width<const N: usize>(words: &str) -> Result<[u8; N]> {
+    let payload = convert_from_pricklybird(words)?;
+    let found = payload.len();
+    payload.try_into().map_err(|_| {
+        DecodeError::General(format!(
+            "Expected a payload of {N} bytes for the integer, found {found} bytes."
+        ))
+    })
+}
+
+/// Number of words `convert_to_pricklybird` produces for a payload of `byte_len` bytes.
+///
+/// Includes the CRC word, empty payloads produce no words.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::encoded_words_len;
+/// assert_eq!(3, encoded_words_len(2));
+/// assert_eq!(0, encoded_words_len(0));
+/// ```
+#[must_use]
+pub const fn encoded_words_len(byte_len: usize) -> usize {
+    if byte_len == 0 {
+        return 0;
+    }
+    byte_len + 1
+}
+
+/// Length in bytes of the string `convert_to_pricklybird` produces for a payload of `byte_len` bytes.
+///
+/// Includes the CRC word and separators, empty payloads produce an empty string.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::encoded_str_len;
+/// assert_eq!("flea-flux-full".len(), encoded_str_len(2));
+/// assert_eq!(0, encoded_str_len(0));
+/// ```
+#[must_use]
+pub const fn encoded_str_len(byte_len: usize) -> usize {
+    let words = encoded_words_len(byte_len);
+    if words == 0 {
+        return 0;
+    }
+    // Four letters per word and one separator between each pair of words.
+    words * 5 - 1
+}
+
+/// Split data into chunks of `chunk_bytes` bytes and convert each to a pricklybird string with its own CRC.
+///
+/// A corrupted chunk can be detected and retransmitted without invalidating the other chunks.
+/// The last chunk is shorter if the data length is not a multiple of `chunk_bytes`.
+/// Chunks are not self describing, they carry no index or total count.
+/// The receiver has to know the framing, e.g. by agreeing on `chunk_bytes`
+/// or transmitting the number of chunks separately.
+///
+/// # Panics
+/// Panics if `chunk_bytes` is zero.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::encode_chunked;
+/// let chunks = encode_chunked(&[0x42, 0x43, 0x42, 0x43, 0x42], 2);
+/// assert_eq!(3, chunks.len());
+/// assert_eq!("flea-flux-full", chunks[0]);
+/// assert_eq!("flea-flux-full", chunks[1]);
+/// ```
+#[must_use]
+pub fn encode_chunked(data: &[u8], chunk_bytes: usize) -> Vec<String> {
+    assert!(chunk_bytes != 0, "Chunk size must not be zero.");
+    data.chunks(chunk_bytes).map(encode_with_crc).collect()
+}
+
+/// Convert chunks created by `encode_chunked` to bytes, checking the CRC of every chunk.
+///
+/// The verified payloads are concatenated in the order the chunks are given.
+/// To find out which chunk is corrupted, decode the chunks individually using `convert_from_pricklybird`.
+///
+/// # Errors
+/// Will return the first error returned by `convert_from_pricklybird` when decoding a chunk.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{decode_chunked, encode_chunked};
+/// let data = [0x42, 0x43, 0x42, 0x43, 0x42];
+/// let chunks = encode_chunked(&data, 2);
+/// let chunk_strs: Vec<&str> = chunks.iter().map(String::as_str).collect();
+/// assert_eq!(data.to_vec(), decode_chunked(&chunk_strs).unwrap());
+/// ```
+pub fn decode_chunked(chunks: &[&str]) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    for chunk in chunks {
+        data.extend(convert_from_pricklybird(chunk)?);
+    }
+    Ok(data)
+}
+
+/// Convert a concatenation of pricklybird strings that are each `code_len` words long to bytes.
+///
+/// The codes are joined by the same `-` as their words, so they are split by counting words.
+/// `code_len` includes the CRC word of each code.
+/// The CRC of every code is checked independently and the payloads are returned in order.
+/// Positions reported by `DecodeError::EmptyWord` and `DecodeError::NonAscii`
+/// refer to the whole input.
+///
+/// # Errors
+/// Will return `DecodeError::General` if `code_len` is smaller than two,
+/// since a code consists of at least one payload word and the CRC word,
+/// or if the number of words in the input is not a multiple of `code_len`.
+///
+/// Will return the first error returned when decoding a code otherwise,
+/// see `convert_from_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decode_concatenated;
+/// let payloads = decode_concatenated("flea-flux-full-flux-flea-call", 3).unwrap();
+/// assert_eq!(vec![vec![0x42, 0x43], vec![0x43, 0x42]], payloads);
+/// assert!(decode_concatenated("flea-flux-full-flux-flea", 3).is_err());
+/// ```
+pub fn decode_concatenated(words: &str, code_len: usize) -> Result<Vec<Vec<u8>>> {
+    if code_len < 2 {
+        return Err(DecodeError::General(
+            "Code length must be at least two words, one payload word and the CRC word.".into(),
+        ));
+    }
+    let word_vec: Vec<&str> = tokens(words).collect();
+    let total_words = separated_word_count(&word_vec);
+    check_word_count(total_words)?;
+    if !total_words.is_multiple_of(code_len) {
+        return Err(DecodeError::General(format!(
+            "Input contains {total_words} words, which is not a multiple of the code length {code_len}."
+        )));
+    }
+    word_vec
+        .chunks_exact(code_len)
+        .enumerate()
+        .map(|(code_index, code)| {
+            let offset = code_index * code_len;
+            decode_with_crc(code).map_err(|err| match err {
+                DecodeError::EmptyWord { index } => DecodeError::EmptyWord {
+                    index: index + offset,
+                },
+                DecodeError::NonAscii { index } => DecodeError::NonAscii {
+                    index: index + offset,
+                },
+                other => other,
+            })
+        })
+        .collect()
+}
+
+/// Largest number of letters a word may differ from its correction
+/// in `convert_from_pricklybird_correcting`.
+const MAX_CORRECTION_DISTANCE: usize = 2;
+
+/// Inverse of `CRC8_TABLE`, undoes the CRC step for a zero byte.
+const CRC8_INVERSE_TABLE: [u8; 256] = invert_table(&CRC8_TABLE);
+
+/// Invert a table that maps every byte to a unique byte.
+#[allow(clippy::cast_possible_truncation)]
+const fn invert_table(table: &[u8; 256]) -> [u8; 256] {
+    let mut result = [0_u8; 256];
+    let mut i = 0;
+    while i < table.len() {
+        result[table[i] as usize] = i as u8;
+        i += 1;
+    }
+    result
+}
+
+/// Convert a pricklybird string to bytes, attempting to correct a single wrong word.
+///
+/// If the CRC does not match, or a single word does not appear in the wordlist,
+/// the word that was most likely mistyped is replaced.
+/// Returns the payload and whether a correction was applied.
+///
+/// For every position there is exactly one replacement word that makes the CRC valid,
+/// so the CRC alone can not locate an error. Instead the replacement that differs from the
+/// received word in the fewest letters is chosen, as expected for a typo.
+/// The correction is only applied if this replacement is unique and differs
+/// in at most two letters.
+///
+/// This correction is a heuristic and can be wrong, particularly for long inputs
+/// or inputs with more than one error. The returned payload should be confirmed by the user.
+/// Use `convert_from_pricklybird` if no correction should be attempted.
+///
+/// # Errors
+/// Will return `DecodeError::General` if:
+/// - The input is empty or a single word, since a payload word and the CRC word are required
+/// - The words in the input are not all four characters long
+/// - Words in the input dont appear in the wordlist and can not be corrected
+///
+/// Will return `DecodeError::EmptyWord` if the input contains a leading,
+/// trailing or repeated separator.
+///
+/// Will return `DecodeError::NonAscii` if a word contains characters other than ASCII letters.
+///
+/// Will return `DecodeError::CRCError` if the CRC does not match and no correction was found.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_correcting;
+/// // The second word should be "flux".
+/// let (data, corrected) = convert_from_pricklybird_correcting("flea-flix-full").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// assert!(corrected);
+/// ```
+pub fn convert_from_pricklybird_correcting(words: &str) -> Result<(Vec<u8>, bool)> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    check_word_count(separated_word_count(&word_vec))?;
+
+    let mut data = Vec::with_capacity(word_vec.len());
+    let mut invalid_index = None;
+    for (index, &word) in word_vec.iter().enumerate() {
+        let mut word_bytes = validate_word_bytes(word.as_bytes(), index)?;
+        word_bytes.make_ascii_lowercase();
+        if let Some(byte) = word_table_lookup(word_bytes) {
+            data.push(byte);
+        } else {
+            if invalid_index.replace(index).is_some() {
+                return Err(DecodeError::General(
+                    "Invalid word detected in input.".into(),
+                ));
+            }
+            // Placeholder, replaced by the correction.
+            data.push(0);
+        }
+    }
+
+    let syndrome = calculate_crc8(&data);
+    if syndrome == 0 && invalid_index.is_none() {
+        // Remove CRC
+        let _ = data.pop();
+        return Ok((data, false));
+    }
+
+    // The CRC is linear, so changing the byte at some position by `delta` changes the CRC
+    // by the CRC of `delta` followed by one zero byte for every later position.
+    // Since the step for a zero byte can be undone using `CRC8_INVERSE_TABLE`, the `delta`
+    // that cancels out the CRC remainder is found by undoing one step per position from the end.
+    let mut best: Option<(usize, u8, usize)> = None;
+    let mut ambiguous = false;
+    let mut delta = syndrome;
+    for index in (0..data.len()).rev() {
+        delta = CRC8_INVERSE_TABLE[delta as usize];
+        if invalid_index.is_some_and(|invalid| invalid != index) {
+            continue;
+        }
+        let candidate = data[index] ^ delta;
+        let distance = letter_distance(word_vec[index], BYTE_WORDLIST[candidate as usize]);
+        match best {
+            Some((_, _, best_distance)) if distance > best_distance => {}
+            Some((_, _, best_distance)) if distance == best_distance => ambiguous = true,
+            _ => {
+                best = Some((index, candidate, distance));
+                ambiguous = false;
+            }
+        }
+    }
+
+    match best {
+        Some((index, candidate, distance)) if !ambiguous && distance <= MAX_CORRECTION_DISTANCE => {
+            data[index] = candidate;
+            // Remove CRC
+            let _ = data.pop();
+            Ok((data, true))
+        }
+        _ if invalid_index.is_some() => Err(DecodeError::General(
+            "Invalid word detected in input.".into(),
+        )),
+        _ => Err(DecodeError::CRCError),
+    }
+}
+
+/// Number of letters that differ between a validated word and a wordlist entry, ignoring case.
+fn letter_distance(word: &str, candidate: [u8; 4]) -> usize {
+    word.bytes()
+        .zip(candidate)
+        .filter(|&(letter, candidate_letter)| letter.to_ascii_lowercase() != candidate_letter)
+        .count()
+}
+
+/// Largest number of words for which `find_transposition` also tries swapping non adjacent words.
+const MAX_TRANSPOSITION_SEARCH_WORDS: usize = 64;
+
+/// Find a swap of two words that makes the CRC of a failing pricklybird string valid.
+///
+/// Returns the indices of the two words, the smaller index first.
+/// Swaps of adjacent words are tried first, since these are the most common transcription error.
+/// For inputs of at most 64 words all other pairs are tried afterwards.
+/// Returns `None` if the input is malformed, the CRC already matches or no swap was found.
+///
+/// Any change to the input makes the CRC match with a probability of about 1 in 256,
+/// so the found swap might not be the actual error, especially for long inputs.
+/// The result should only be presented as a hint to the user.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::find_transposition;
+/// assert_eq!(Some((0, 1)), find_transposition("flux-flea-full"));
+/// assert_eq!(None, find_transposition("flea-flux-full"));
+/// ```
+#[must_use]
+pub fn find_transposition(words: &str) -> Option<(usize, usize)> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    check_word_count(separated_word_count(&word_vec)).ok()?;
+    let mut data = words_to_bytes(&word_vec).ok()?;
+    if calculate_crc8(&data) == 0 {
+        return None;
+    }
+
+    let mut swap_is_valid = |first: usize, second: usize| {
+        if data[first] == data[second] {
+            return false;
+        }
+        data.swap(first, second);
+        let valid = calculate_crc8(&data) == 0;
+        data.swap(first, second);
+        valid
+    };
+    let total_words = word_vec.len();
+    if let Some(first) = (0..total_words - 1).find(|&first| swap_is_valid(first, first + 1)) {
+        return Some((first, first + 1));
+    }
+    if total_words > MAX_TRANSPOSITION_SEARCH_WORDS {
+        return None;
+    }
+    (0..total_words).find_map(|first| {
+        (first + 2..total_words)
+            .find(|&second| swap_is_valid(first, second))
+            .map(|second| (first, second))
+    })
+}
+
+/// Difference at a single position between an expected and an actual pricklybird string,
+/// returned by `diff_codes`.
+///
+/// Words are compared ignoring case and reported in lowercase.
+/// Positions are indices into the words of the expected string.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WordDiff {
+    /// The word matches the expected word.
+    Match {
+        /// Position of the word in the expected string.
+        position: usize,
+        /// The matching word.
+        word: String,
+    },
+    /// Another word was found in place of the expected word.
+    Substituted {
+        /// Position of the word in the expected string.
+        position: usize,
+        /// The expected word.
+        expected: String,
+        /// The word found instead.
+        actual: String,
+    },
+    /// The expected word is missing.
+    Missing {
+        /// Position of the word in the expected string.
+        position: usize,
+        /// The missing word.
+        expected: String,
+    },
+    /// A word was found that is not expected.
+    Extra {
+        /// Position in the expected string before which the word was inserted.
+        position: usize,
+        /// The unexpected word.
+        actual: String,
+    },
+}
+
+/// Compare two pricklybird strings word by word.
+///
+/// The words are aligned using the smallest number of substitutions, missing and extra words,
+/// so a single missing word does not make all following words appear substituted.
+/// Since only the words are compared, this also works if neither string can be decoded.
+/// Both strings are trimmed and split at `-` like in `convert_from_pricklybird`.
+/// Takes time and memory proportional to the product of the word counts,
+/// which is intended for comparing human typed codes.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{WordDiff, diff_codes};
+/// let diff = diff_codes("flea-flux-full", "flea-FLAX-full");
+/// assert_eq!(
+///     WordDiff::Substituted { position: 1, expected: "flux".into(), actual: "flax".into() },
+///     diff[1]
+/// );
+/// let diff = diff_codes("flea-flux-full", "flea-full");
+/// assert_eq!(WordDiff::Missing { position: 1, expected: "flux".into() }, diff[1]);
+/// ```
+#[must_use]
+pub fn diff_codes(expected: &str, actual: &str) -> Vec<WordDiff> {
+    let split_lowercase = |words: &str| -> Vec<String> {
+        if words.trim().is_empty() {
+            return Vec::new();
+        }
+        tokens(words).map(str::to_ascii_lowercase).collect()
+    };
+    let expected_words = split_lowercase(expected);
+    let actual_words = split_lowercase(actual);
+
+    // distances[i][j] is the edit distance between the first i expected and the first j actual words.
+    let columns = actual_words.len() + 1;
+    let mut distances = vec![0_usize; (expected_words.len() + 1) * columns];
+    for i in 0..=expected_words.len() {
+        for j in 0..columns {
+            distances[i * columns + j] = match (i, j) {
+                (0, _) => j,
+                (_, 0) => i,
+                _ => {
+                    let substitution = usize::from(expected_words[i - 1] != actual_words[j - 1]);
+                    (distances[(i - 1) * columns + j - 1] + substitution)
+                        .min(distances[(i - 1) * columns + j] + 1)
+                        .min(distances[i * columns + j - 1] + 1)
+                }
+            };
+        }
+    }
+
+    // Walk back from the end, preferring matches and substitutions.
+    let mut diff = Vec::with_capacity(expected_words.len().max(actual_words.len()));
+    let (mut i, mut j) = (expected_words.len(), actual_words.len());
+    while i > 0 || j > 0 {
+        let current = distances[i * columns + j];
+        if i > 0 && j > 0 {
+            let same = expected_words[i - 1] == actual_words[j - 1];
+            if current == distances[(i - 1) * columns + j - 1] + usize::from(!same) {
+                diff.push(if same {
+                    WordDiff::Match {
+                        position: i - 1,
+                        word: actual_words[j - 1].clone(),
+                    }
+                } else {
+                    WordDiff::Substituted {
+                        position: i - 1,
+                        expected: expected_words[i - 1].clone(),
+                        actual: actual_words[j - 1].clone(),
+                    }
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && current == distances[(i - 1) * columns + j] + 1 {
+            diff.push(WordDiff::Missing {
+                position: i - 1,
+                expected: expected_words[i - 1].clone(),
+            });
+            i -= 1;
+        } else {
+            diff.push(WordDiff::Extra {
+                position: i,
+                actual: actual_words[j - 1].clone(),
+            });
+            j -= 1;
+        }
+    }
+    diff.reverse();
+    diff
+}
+
+/// Convert a pricklybird string to bytes and check CRC, taking time independent of the word contents.
+///
+/// Intended for codes that encode secrets such as recovery keys,
+/// where an attacker can submit guesses and measure how long decoding takes.
+/// `convert_from_pricklybird` returns on the first invalid word,
+/// which reveals how far a guess matches. This function instead decodes every word
+/// by comparing it against the whole wordlist, computes the CRC without table lookups
+/// and combines the results using bitwise operations,
+/// so that only a single error is returned at the end.
+///
+/// The protection is limited:
+/// - The number of words and the length of each word still leak through timing
+/// - The compiler gives no guarantee that the bitwise operations stay free of branches
+/// - Decoding is roughly 256 times slower than `convert_from_pricklybird`
+///
+/// Use `convert_from_pricklybird` unless timing side channels are a concern.
+///
+/// # Errors
+/// Will return `DecodeError::General` if:
+/// - The input is empty or a single word, since a payload word and the CRC word are required
+/// - Any word is not four ASCII letters long or does not appear in the wordlist
+///
+/// Will return `DecodeError::CRCError` if all words are valid but the CRC value does not match the input.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::convert_from_pricklybird_ct;
+/// let data = convert_from_pricklybird_ct("flea-flux-full").unwrap();
+/// assert_eq!(vec![0x42, 0x43], data);
+/// assert!(convert_from_pricklybird_ct("flea-flax-full").is_err());
+/// ```
+pub fn convert_from_pricklybird_ct(words: &str) -> Result<Vec<u8>> {
+    let word_vec: Vec<&str> = tokens(words).collect();
+    check_word_count(separated_word_count(&word_vec))?;
+
+    let mut all_valid = 0xFF_u8;
+    let mut payload = Vec::with_capacity(word_vec.len());
+    for word in &word_vec {
+        let (byte_value, word_valid) = decode_word_ct(word.as_bytes());
+        all_valid &= word_valid;
+        payload.push(byte_value);
+    }
+    let crc_ok = ct_mask(calculate_crc8_ct(&payload) == 0);
+
+    // Branching is fine from here on, the result is revealed to the caller anyway.
+    if all_valid == 0 {
         return Err(DecodeError::General(
-            "Input must be at least two words long.".into(),
+            "Invalid word detected in input.".to_owned(),
+        ));
+    }
+    if crc_ok == 0 {
+        return Err(DecodeError::CRCError);
+    }
+    // Remove CRC
+    let _ = payload.pop();
+    Ok(payload)
+}
+
+/// Turn a condition into a mask with all bits set if it is true and none set if it is false.
+const fn ct_mask(condition: bool) -> u8 {
+    0_u8.wrapping_sub(condition as u8)
+}
+
+/// Decode a single word by comparing it against every wordlist entry.
+///
+/// Returns the byte value and a mask that has all bits set if the word is valid.
+fn decode_word_ct(word: &[u8]) -> (u8, u8) {
+    let mut valid = ct_mask(word.len() == 4);
+    let mut word_lower = [0_u8; 4];
+    for (index, letter_lower) in word_lower.iter_mut().enumerate() {
+        // Setting this bit maps ASCII uppercase to lowercase letters
+        // and leaves every byte that is not a letter outside of `a-z`.
+        let letter = word.get(index).copied().unwrap_or(0) | 0x20;
+        valid &= ct_mask(letter.wrapping_sub(b'a') < 26);
+        *letter_lower = letter;
+    }
+
+    let mut byte_value = 0_u8;
+    let mut found = 0_u8;
+    for (candidate, entry) in (0..=u8::MAX).zip(BYTE_WORDLIST) {
+        let difference = entry
+            .iter()
+            .zip(word_lower)
+            .fold(0_u8, |acc, (&entry_letter, letter)| {
+                acc | (entry_letter ^ letter)
+            });
+        let is_match = ct_mask(difference == 0);
+        byte_value |= candidate & is_match;
+        found |= is_match;
+    }
+    (byte_value, valid & found)
+}
+
+/// Calculate the CRC-8 bit by bit, avoiding the data dependent memory access of `CRC8_TABLE`.
+fn calculate_crc8_ct(data: &[u8]) -> u8 {
+    let mut crc = 0_u8;
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..u8::BITS {
+            crc = (crc << 1) ^ (ct_mask(crc & 0x80 != 0) & constants::CRC8_POLY);
+        }
+    }
+    crc
+}
+
+/// Count the words in a pricklybird string, including the CRC word.
+///
+/// Surrounding whitespace is ignored and words are separated by `-`.
+/// The words are not validated, use `convert_from_pricklybird` for that.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::word_count;
+/// assert_eq!(3, word_count("flea-flux-full"));
+/// assert_eq!(0, word_count(" "));
+/// ```
+#[must_use]
+pub fn word_count(words: &str) -> usize {
+    if words.trim().is_empty() {
+        return 0;
+    }
+    tokens(words).count()
+}
+
+/// Number of payload bytes a pricklybird string decodes to.
+///
+/// This is the word count without the CRC word.
+/// The words are not validated, use `convert_from_pricklybird` for that.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::decoded_len;
+/// assert_eq!(2, decoded_len("flea-flux-full"));
+/// assert_eq!(0, decoded_len(""));
+/// ```
+#[must_use]
+pub fn decoded_len(words: &str) -> usize {
+    word_count(words).saturating_sub(1)
+}
+
+/// Conversion between binary data and a textual representation.
+///
+/// Allows switching between encodings at runtime, for example using `Box<dyn Codec>`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::{Codec, Pricklybird};
+/// let codec: Box<dyn Codec> = Box::new(Pricklybird);
+/// let code = codec.encode(&[0x42, 0x43]);
+/// assert_eq!("flea-flux-full", code);
+/// assert_eq!(vec![0x42, 0x43], codec.decode(&code).unwrap());
+/// ```
+pub trait Codec {
+    /// Convert `data` to its textual representation.
+    fn encode(&self, data: &[u8]) -> String;
+
+    /// Convert a textual representation back to the original data.
+    ///
+    /// # Errors
+    /// Will return `DecodeError` if the input is not a valid encoding.
+    fn decode(&self, s: &str) -> Result<Vec<u8>>;
+}
+
+/// The standard pricklybird encoding as a `Codec`.
+///
+/// Delegates to `convert_to_pricklybird` and `convert_from_pricklybird`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Pricklybird;
+
+impl Codec for Pricklybird {
+    fn encode(&self, data: &[u8]) -> String {
+        convert_to_pricklybird(data)
+    }
+
+    fn decode(&self, s: &str) -> Result<Vec<u8>> {
+        convert_from_pricklybird(s)
+    }
+}
+
+/// Check that the input contains at least one payload word and the CRC word.
+fn check_word_count(total_words: usize) -> Result<()> {
+    match total_words {
+        0 => Err(DecodeError::General("Input is empty.".into())),
+        // A single word could only be a CRC without any payload.
+        1 => Err(DecodeError::General(
+            "Input consists of a single word, at least one payload word and the CRC word are required."
+                .into(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Count already separated words, input that was empty after trimming counts as zero words.
+fn separated_word_count(word_vec: &[&str]) -> usize {
+    if let [""] = word_vec {
+        return 0;
+    }
+    word_vec.len()
+}
+
+/// Decode already separated words, check the CRC and remove it from the result.
+fn decode_with_crc(word_vec: &[&str]) -> Result<Vec<u8>> {
+    decode_with_crc_using(word_vec, word_table_lookup)
+}
+
+/// Decode already separated words like `decode_with_crc`, looking up each word using `lookup`.
+fn decode_with_crc_using(
+    word_vec: &[&str],
+    lookup: impl Fn([u8; 4]) -> Option<u8>,
+) -> Result<Vec<u8>> {
+    let result = decode_checked_using(word_vec, lookup)?;
+    if !result.crc_ok {
+        return Err(DecodeError::CRCError);
+    }
+    Ok(result.payload)
+}
+
+/// Decode already separated words, remove the CRC and report if it was valid.
+fn decode_checked(word_vec: &[&str]) -> Result<DecodeResult> {
+    decode_checked_using(word_vec, word_table_lookup)
+}
+
+/// Decode already separated words like `decode_checked`, looking up each word using `lookup`.
+fn decode_checked_using(
+    word_vec: &[&str],
+    lookup: impl Fn([u8; 4]) -> Option<u8>,
+) -> Result<DecodeResult> {
+    check_word_count(separated_word_count(word_vec))?;
+
+    let mut payload = words_to_bytes_using(word_vec, lookup)?;
+    let crc_ok = calculate_crc8(&payload) == 0;
+    // Remove CRC
+    let _ = payload.pop();
+    Ok(DecodeResult { payload, crc_ok })
+}
+
+/// Test the conversion from and to pricklybird.
+#[cfg(test)]
+mod pricklybird_tests {
+    use super::*;
+    /// Seed used to generate test data using `testing::deterministic_array`.
+    const TEST_DATA_SEED: u128 = 1;
+    /// How many byes of test data to use for conversion tests.
+    const TEST_DATA_BYTES: usize = 4096;
+    /// Pseudorandom data used to test conversion functions.
+    const TEST_DATA: [u8; TEST_DATA_BYTES] = testing::deterministic_array(TEST_DATA_SEED);
+
+    /// Encode `data` as described by the specification, independently of the library code.
+    ///
+    /// Uses a bitwise CRC-8 and indexes the wordlist directly, sharing no helpers
+    /// with `convert_to_pricklybird`, so the two implementations can be compared.
+    fn spec_reference_encode(data: &[u8]) -> String {
+        if data.is_empty() {
+            return String::new();
+        }
+        let mut crc = 0_u8;
+        for &byte in data {
+            crc ^= byte;
+            for _ in 0..8 {
+                crc = if crc & 0x80 == 0 {
+                    crc << 1
+                } else {
+                    (crc << 1) ^ 0x1D
+                };
+            }
+        }
+        let mut words: Vec<&str> = data
+            .iter()
+            .map(|&byte| WORDLIST[usize::from(byte)])
+            .collect();
+        words.push(WORDLIST[usize::from(crc)]);
+        words.join("-")
+    }
+
+    /// Check the transcription safety estimates against the CRC guarantees.
+    #[test]
+    fn test_transcription_safety() {
+        for byte_len in [1, 2, 16, 30] {
+            let safety = transcription_safety(byte_len);
+            assert!(
+                safety.crc8_adequate,
+                "CRC-8 inadequate for {byte_len} bytes."
+            );
+            assert_eq!(encoded_words_len(byte_len), safety.words);
+        }
+        for byte_len in [31, 64, 4096] {
+            assert!(
+                !transcription_safety(byte_len).crc8_adequate,
+                "CRC-8 adequate for {byte_len} bytes."
+            );
+        }
+
+        // Every single word substitution is detected.
+        let data = &TEST_DATA[..8];
+        let coded_words = convert_to_pricklybird(data);
+        let words: Vec<&str> = coded_words.split('-').collect();
+        for position in 0..words.len() {
+            for replacement in WORDLIST.iter().filter(|&&word| word != words[position]) {
+                let mut modified = words.clone();
+                modified[position] = replacement;
+                assert_eq!(
+                    Err(DecodeError::CRCError),
+                    convert_from_pricklybird(&modified.join("-")),
+                    "Substitution at {position} with {replacement} not detected."
+                );
+            }
+        }
+        assert!(
+            transcription_safety(8)
+                .single_word_undetected
+                .total_cmp(&0.0)
+                .is_eq(),
+            "Single word errors reported as undetectable."
+        );
+        assert!(
+            transcription_safety(8)
+                .multi_word_undetected
+                .total_cmp(&(1.0 / 256.0))
+                .is_eq(),
+            "Multi word error estimate is not 1 in 256."
+        );
+    }
+
+    /// Check that only repeated single byte payloads are reported as degenerate.
+    #[test]
+    fn test_is_degenerate() {
+        for byte in [0x00_u8, 0x42, 0xFF] {
+            assert!(
+                is_degenerate(&[byte; 2]),
+                "Repeated {byte:#x} not detected."
+            );
+            assert!(
+                is_degenerate(&[byte; 64]),
+                "Repeated {byte:#x} not detected."
+            );
+        }
+        assert!(!is_degenerate(&[]));
+        assert!(!is_degenerate(&[0x00]));
+        assert!(!is_degenerate(&[0x00, 0x00, 0x01]));
+        assert!(!is_degenerate(&TEST_DATA));
+    }
+
+    /// Check that the library agrees with the independent reference implementation.
+    #[test]
+    fn test_spec_reference_encode() {
+        assert_eq!("flea-flux-full", spec_reference_encode(&[0x42, 0x43]));
+        for len in [0, 1, 2, 31, 32, 255, TEST_DATA_BYTES] {
+            assert_eq!(
+                spec_reference_encode(&TEST_DATA[..len]),
+                convert_to_pricklybird(&TEST_DATA[..len]),
+                "Library and reference implementation disagree for {len} bytes."
+            );
+        }
+    }
+
+    /// Test the standard vectors supplied with the specification.
+    #[test]
+    fn test_vectors() {
+        let test_vectors = vec![
+            (vec![0xDE_u8, 0xAD, 0xBE, 0xEF], "turf-port-rust-warn-void"),
+            (vec![0x42_u8, 0x43], "flea-flux-full"),
+            (
+                vec![0x12_u8, 0x34, 0x56, 0x78, 0x90],
+                "blob-eggs-hair-king-meta-yell",
+            ),
+            (vec![0_u8; 5], "acid-acid-acid-acid-acid-acid"),
+            (vec![0xFF_u8; 5], "zone-zone-zone-zone-zone-sand"),
+        ];
+
+        for (data, words) in test_vectors {
+            // Test converting bytes to pricklybird.
+            assert_eq!(
+                words,
+                convert_to_pricklybird(&data),
+                "Failed to convert {data:?} test vector to pricklybird."
+            );
+
+            // Test converting pricklybird to bytes.
+            assert_eq!(
+                data,
+                convert_from_pricklybird(words).unwrap(),
+                "Failed to convert {words} test vector to bytes."
+            );
+        }
+    }
+
+    /// Check that concatenated codes are split by their length and verified independently.
+    #[test]
+    fn test_decode_concatenated() {
+        let first_code = convert_to_pricklybird(&TEST_DATA[..8]);
+        let second_code = convert_to_pricklybird(&TEST_DATA[8..16]);
+        let concatenated = format!("{first_code}-{second_code}\n");
+        assert_eq!(
+            vec![TEST_DATA[..8].to_vec(), TEST_DATA[8..16].to_vec()],
+            decode_concatenated(&concatenated, 9).unwrap()
+        );
+        assert_eq!(
+            vec![TEST_DATA[..8].to_vec()],
+            decode_concatenated(&first_code, 9).unwrap()
+        );
+
+        // Each code is checked with its own CRC.
+        let swapped = format!("{second_code}-{first_code}");
+        assert_eq!(2, decode_concatenated(&swapped, 9).unwrap().len());
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            decode_concatenated(&concatenated, 6)
+        );
+
+        assert_eq!(
+            Err(DecodeError::General(
+                "Input contains 18 words, which is not a multiple of the code length 4.".into()
+            )),
+            decode_concatenated(&concatenated, 4)
+        );
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 4 }),
+            decode_concatenated("flea-flux-full-flux--call", 3)
+        );
+        assert_eq!(
+            Err(DecodeError::General("Input is empty.".into())),
+            decode_concatenated(" ", 3)
+        );
+        assert!(
+            decode_concatenated("flea-flux-full", 1).is_err(),
+            "Code length of one was accepted."
+        );
+        assert!(
+            decode_concatenated("flea-flux-full", 0).is_err(),
+            "Code length of zero was accepted."
+        );
+    }
+
+    /// Check that the known test vectors are identified by their code.
+    #[test]
+    fn test_identify_known_vector() {
+        for (label, payload) in KNOWN_VECTORS {
+            assert_eq!(
+                Some(label),
+                identify_known_vector(&convert_to_pricklybird(payload)),
+                "Test vector '{label}' was not identified."
+            );
+        }
+        assert_eq!(
+            Some("4243 example"),
+            identify_known_vector(" FLEA-FLUX-FULL\n")
+        );
+        assert_eq!(
+            Some("five zero bytes"),
+            identify_known_vector("acid-acid-acid-acid-acid-acid")
+        );
+        assert_eq!(None, identify_known_vector("flux-flea-call"));
+        assert_eq!(None, identify_known_vector("flea-flux-flux"));
+        assert_eq!(None, identify_known_vector(""));
+    }
+
+    /// Test the vectors listed in `tests/vectors.txt`.
+    #[test]
+    fn test_vector_file() {
+        /// Test vectors as `hex,code` lines.
+        const VECTOR_FILE: &str = include_str!("../tests/vectors.txt");
+
+        let mut vector_count = 0;
+        for (line_index, line) in VECTOR_FILE.lines().enumerate() {
+            let line_number = line_index + 1;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (hex, words) = line
+                .split_once(',')
+                .unwrap_or_else(|| panic!("Line {line_number} of the vector file has no comma."));
+            assert!(
+                hex.len().is_multiple_of(2),
+                "Line {line_number} of the vector file has an odd number of hex digits."
+            );
+            let data: Vec<u8> = (0..hex.len())
+                .step_by(2)
+                .map(|start| {
+                    u8::from_str_radix(&hex[start..start + 2], 16).unwrap_or_else(|_| {
+                        panic!("Line {line_number} of the vector file contains invalid hex.")
+                    })
+                })
+                .collect();
+
+            assert_eq!(
+                words,
+                convert_to_pricklybird(&data),
+                "Failed to convert vector on line {line_number} to pricklybird."
+            );
+            assert_eq!(
+                data,
+                convert_from_pricklybird(words).unwrap(),
+                "Failed to convert vector on line {line_number} to bytes."
+            );
+            vector_count += 1;
+        }
+        assert!(vector_count > 0, "The vector file contains no vectors.");
+    }
+
+    /// Test conversion to and from pricklybird on pseudorandom test data.
+    #[test]
+    fn test_simple_conversion() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let decoded_data = convert_from_pricklybird(&coded_words).unwrap();
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            decoded_data,
+            "Converter did not correctly encode or decode data."
+        );
+    }
+
+    /// Test that pricklybird input containing mixed case is properly decoded.
+    #[test]
+    fn test_uppercase() {
+        assert_eq!(
+            vec![0xDE_u8, 0xAD, 0xBE, 0xEF],
+            convert_from_pricklybird("TUrF-Port-RUST-warn-vOid").unwrap(),
+            "Converter did not correctly decode uppercase data."
+        );
+    }
+
+    /// Test that replacing a pricklybird word is detected using the CRC-8.
+    #[test]
+    fn test_error_detection_bit_flip() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let mut corrupt_data = TEST_DATA;
+        corrupt_data[0] ^= 1;
+        let incorrect_word =
+            String::from_utf8(bytes_to_words(&corrupt_data[0..1])[0].to_vec()).unwrap();
+        let incorrect_coded_words = format!("{}{}", &incorrect_word[..4], &coded_words[4..]);
+        assert!(
+            convert_from_pricklybird(&incorrect_coded_words).is_err(),
+            "Converter did not detect error in corrupted input."
+        );
+    }
+
+    /// Check that swapping two adjacent words is detected using the CRC-8.
+    #[test]
+    fn test_error_detection_adjacent_swap() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let mut word_vec: Vec<&str> = coded_words.split('-').collect();
+        word_vec.swap(0, 1);
+        let swapped_coded_words = word_vec.join("-");
+        assert!(
+            matches!(
+                convert_from_pricklybird(&swapped_coded_words),
+                Err(DecodeError::CRCError)
+            ),
+            "Converter did not detect error caused by word swap."
+        );
+    }
+
+    /// Check that codes with the CRC word in front round trip and detect swaps.
+    #[test]
+    fn test_crc_first() {
+        let coded_words = convert_to_pricklybird_crc_first(&TEST_DATA);
+        let crc_word = WORDLIST[calculate_crc8(&TEST_DATA) as usize];
+        assert!(
+            coded_words.starts_with(crc_word),
+            "CRC word is not in front of the payload."
+        );
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_crc_first(&coded_words).unwrap(),
+            "Converter did not correctly encode or decode data with CRC first."
+        );
+        assert_eq!("", convert_to_pricklybird_crc_first(&[]));
+
+        let mut word_vec: Vec<&str> = coded_words.split('-').collect();
+        word_vec.swap(1, 2);
+        let swapped_coded_words = word_vec.join("-");
+        assert!(
+            matches!(
+                convert_from_pricklybird_crc_first(&swapped_coded_words),
+                Err(DecodeError::CRCError)
+            ),
+            "Converter did not detect error caused by word swap with CRC first."
+        );
+        assert!(
+            matches!(
+                convert_from_pricklybird_crc_first("flea-full-flux"),
+                Err(DecodeError::CRCError)
+            ),
+            "Converter did not detect error caused by swap with the CRC word."
+        );
+    }
+
+    /// Check that the predicted output length matches the actual output.
+    #[test]
+    fn test_encoded_len() {
+        let test_vectors = [
+            (vec![0xDE_u8, 0xAD, 0xBE, 0xEF], "turf-port-rust-warn-void"),
+            (vec![0x42_u8], "flea-deer"),
+            (vec![], ""),
+        ];
+        for (data, words) in test_vectors {
+            assert_eq!(words.len(), encoded_str_len(data.len()));
+            assert_eq!(word_count(words), encoded_words_len(data.len()));
+        }
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(coded_words.len(), encoded_str_len(TEST_DATA.len()));
+        assert_eq!(word_count(&coded_words), encoded_words_len(TEST_DATA.len()));
+    }
+
+    /// Check that single mistyped words are corrected.
+    #[test]
+    fn test_correcting() {
+        assert_eq!(
+            Ok((vec![0x42_u8, 0x43], false)),
+            convert_from_pricklybird_correcting("flea-flux-full")
+        );
+        // Typos resulting in words that are not in the wordlist.
+        for typo_code in [
+            "flea-flix-full",
+            "FLEA-flux-fall",
+            "flaa-flux-full",
+            "flea-fulx-full",
+        ] {
+            assert_eq!(
+                Ok((vec![0x42_u8, 0x43], true)),
+                convert_from_pricklybird_correcting(typo_code),
+                "Corrector did not correct {typo_code}."
+            );
+        }
+
+        // Typo resulting in a different word from the wordlist, "beef" became "beer".
+        let data = [0x0D_u8, 0x42, 0x43];
+        let code = convert_to_pricklybird(data);
+        assert!(code.starts_with("beef-"));
+        let typo_code = code.replacen("beef", "beer", 1);
+        assert_eq!(
+            Ok((data.to_vec(), true)),
+            convert_from_pricklybird_correcting(&typo_code),
+            "Corrector did not correct {typo_code}."
+        );
+
+        assert!(
+            convert_from_pricklybird_correcting("flix-flax-full").is_err(),
+            "Corrector accepted multiple invalid words."
+        );
+        assert!(
+            convert_from_pricklybird_correcting("flea-qqqq-full").is_err(),
+            "Corrector accepted a correction differing in too many letters."
+        );
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 1 }),
+            convert_from_pricklybird_correcting("flea--full")
+        );
+    }
+
+    /// Check that whitespace is correctly trimmed.
+    #[test]
+    fn test_whitespace_trim() {
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            convert_from_pricklybird(" \t\n\r\x0b\x0c flea-flux-full \t\n\r\x0b\x0c ").unwrap()
+        );
+    }
+
+    /// Check that edge cases result in the correct errors.
+    #[test]
+    fn test_unusual_input() {
+        let edge_cases = vec![
+            ("", "empty input"),
+            ("orca", "input to short"),
+            ("a\u{ae}\u{bf}a-orca", "invalid characters in input"),
+            (
+                "g\u{e4}sp-risk-king-orca-husk",
+                "invalid characters in input",
+            ),
+            ("-risk-king-orca-husk", "incorrectly formatted input"),
+            ("gasp-rock-king-orca-husk", "incorrect word in input"),
+            ("flea- \t \t-full", "whitespace in input"),
+            ("flea-aaa\0-full", "null bytes in input"),
+            ("flea-\0aaa-full", "null bytes in input"),
+            ("flea-\x7faaa-full", "ASCII control character in input"),
+            ("flea-aaa\x7f-full", "ASCII control character in input"),
+            ("flea-\x7f\x7f\x7f\x7f-full", "DEL characters in input"),
+            ("flea-fl\u{80}-full", "high bit bytes in input"),
+            ("flea-\u{7ff}lu-full", "high bit bytes in input"),
+            // Check that no index out of bound error is thrown when the highest
+            // possible value is used to index the hash table.
+            ("zzzz-king", "incorrect word in input"),
+        ];
+        for (edge_case_input, error_reason) in edge_cases {
+            assert!(
+                convert_from_pricklybird(edge_case_input).is_err(),
+                "Converter did not return error for: {error_reason} ({edge_case_input})"
+            );
+        }
+    }
+
+    /// Check that the checked decoder returns the payload even if the CRC is invalid.
+    #[test]
+    fn test_checked_decode() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let valid_result = convert_from_pricklybird_checked(&coded_words).unwrap();
+        assert!(
+            valid_result.crc_ok,
+            "Checked decoder reported invalid CRC for valid input."
+        );
+        assert_eq!(TEST_DATA.to_vec(), valid_result.payload);
+
+        let mut word_vec: Vec<&str> = coded_words.split('-').collect();
+        word_vec.swap(0, 1);
+        let swapped_coded_words = word_vec.join("-");
+        let swapped_result = convert_from_pricklybird_checked(&swapped_coded_words).unwrap();
+        assert!(
+            !swapped_result.crc_ok,
+            "Checked decoder did not detect word swap."
+        );
+        let mut swapped_data = TEST_DATA;
+        swapped_data.swap(0, 1);
+        assert_eq!(swapped_data.to_vec(), swapped_result.payload);
+
+        assert!(
+            convert_from_pricklybird_checked("flea-flax-full").is_err(),
+            "Checked decoder did not return error for invalid word."
+        );
+    }
+
+    /// Check that versioned codes round trip and that the version is checked.
+    #[test]
+    fn test_versioned() {
+        let coded_words = convert_to_pricklybird_versioned(&TEST_DATA);
+        assert!(coded_words.starts_with("v1:"));
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_versioned(&coded_words).unwrap(),
+            "Versioned converter did not correctly encode or decode data."
+        );
+        assert_eq!(
+            Err(DecodeError::VersionMismatch {
+                found: "v0".to_owned(),
+                expected: PRICKLYBIRD_VERSION
+            }),
+            convert_from_pricklybird_versioned("v0:flea-flux-full")
+        );
+        assert!(
+            matches!(
+                convert_from_pricklybird_versioned("flea-flux-full"),
+                Err(DecodeError::General(_))
+            ),
+            "Versioned converter accepted input without version prefix."
+        );
+        assert!(
+            matches!(
+                convert_from_pricklybird_versioned("v1:flux-flea-full"),
+                Err(DecodeError::CRCError)
+            ),
+            "Versioned converter did not detect word swap."
+        );
+    }
+
+    /// Check that extra separators result in an empty word error at the correct position.
+    #[test]
+    fn test_empty_word() {
+        let edge_cases = [
+            ("flea--flux-full", 1),
+            ("-flea-flux-full", 0),
+            ("flea-flux-full-", 3),
+            ("flea-flux--", 2),
+        ];
+        for (edge_case_input, expected_index) in edge_cases {
+            assert_eq!(
+                Err(DecodeError::EmptyWord {
+                    index: expected_index
+                }),
+                convert_from_pricklybird(edge_case_input),
+                "Converter did not detect empty word in {edge_case_input}."
+            );
+        }
+    }
+
+    /// Check that codes with alternative separators decode when using the flexible decoder.
+    #[test]
+    fn test_flexible_separators() {
+        let inputs = [
+            "flea-flux-full",
+            "flea flux full",
+            "flea\u{b7}flux\u{b7}full",
+            "flea\u{2013}flux\u{2014}full",
+            "  FLEA -- flux_.full\n",
+        ];
+        for input in inputs {
+            assert_eq!(
+                vec![0x42_u8, 0x43],
+                convert_from_pricklybird_flexible(input).unwrap(),
+                "Flexible decoder did not correctly decode {input:?}."
+            );
+        }
+
+        let edge_cases = [
+            ("flea1-flux-full", "digit attached to word"),
+            ("flea-flux2full", "digit used as separator"),
+            ("g\u{e4}sp-risk-king-orca-husk", "non ASCII letter in word"),
+            ("flea", "input to short"),
+            ("", "empty input"),
+            ("flux-flea-full", "incorrect CRC"),
+        ];
+        for (edge_case_input, error_reason) in edge_cases {
+            assert!(
+                convert_from_pricklybird_flexible(edge_case_input).is_err(),
+                "Flexible decoder did not return error for: {error_reason} ({edge_case_input})"
+            );
+        }
+    }
+
+    /// Check the alignment of substituted, missing and extra words.
+    #[test]
+    fn test_diff_codes() {
+        let matches = |words: &str| -> Vec<WordDiff> {
+            words
+                .split('-')
+                .enumerate()
+                .map(|(position, word)| WordDiff::Match {
+                    position,
+                    word: word.into(),
+                })
+                .collect()
+        };
+        assert_eq!(
+            matches("flea-flux-full"),
+            diff_codes("flea-flux-full", " FLEA-flux-Full\n")
+        );
+        assert_eq!(
+            vec![
+                WordDiff::Substituted {
+                    position: 0,
+                    expected: "flea".into(),
+                    actual: "flux".into(),
+                },
+                WordDiff::Substituted {
+                    position: 1,
+                    expected: "flux".into(),
+                    actual: "flea".into(),
+                },
+                WordDiff::Match {
+                    position: 2,
+                    word: "full".into(),
+                },
+            ],
+            diff_codes("flea-flux-full", "flux-flea-full")
+        );
+        assert_eq!(
+            vec![
+                WordDiff::Match {
+                    position: 0,
+                    word: "flea".into(),
+                },
+                WordDiff::Extra {
+                    position: 1,
+                    actual: "acid".into(),
+                },
+                WordDiff::Match {
+                    position: 1,
+                    word: "flux".into(),
+                },
+                WordDiff::Match {
+                    position: 2,
+                    word: "full".into(),
+                },
+            ],
+            diff_codes("flea-flux-full", "flea-acid-flux-full")
+        );
+
+        // A missing word in a long code only affects its own position.
+        let coded_words = convert_to_pricklybird(&TEST_DATA[..32]);
+        let mut words: Vec<&str> = coded_words.split('-').collect();
+        let removed = words.remove(10);
+        let diff = diff_codes(&coded_words, &words.join("-"));
+        assert_eq!(words.len() + 1, diff.len());
+        assert_eq!(
+            vec![&WordDiff::Missing {
+                position: 10,
+                expected: removed.into(),
+            }],
+            diff.iter()
+                .filter(|entry| !matches!(entry, WordDiff::Match { .. }))
+                .collect::<Vec<_>>()
+        );
+
+        assert!(diff_codes("", " ").is_empty(), "Empty inputs differ.");
+        assert_eq!(
+            vec![WordDiff::Extra {
+                position: 0,
+                actual: "flea".into(),
+            }],
+            diff_codes("", "flea")
+        );
+    }
+
+    /// Check that fields are grouped as requested and invalid field sizes are rejected.
+    #[test]
+    fn test_convert_to_pricklybird_fielded() {
+        let data = &TEST_DATA[..6];
+        let fielded_words = convert_to_pricklybird_fielded(data, &[2, 3, 1], '.').unwrap();
+        let fields: Vec<&str> = fielded_words.split('.').collect();
+        assert_eq!(
+            vec![2, 3, 1, 1],
+            fields
+                .iter()
+                .map(|field| field.split('-').count())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            convert_to_pricklybird(data),
+            fielded_words.replace('.', "-"),
+            "Fields do not contain the words in order."
+        );
+        assert_eq!(
+            data.to_vec(),
+            convert_from_pricklybird_flexible(&fielded_words).unwrap()
+        );
+
+        // Every valid group separator results in a code the flexible decoder accepts.
+        for group_separator in [' ', '.', '_', '/', '+', '~', '\u{b7}'] {
+            let code = convert_to_pricklybird_fielded(data, &[2, 3, 1], group_separator).unwrap();
+            assert_eq!(
+                data.to_vec(),
+                convert_from_pricklybird_flexible(&code).unwrap(),
+                "Fields separated by '{}' do not round trip.",
+                group_separator.escape_default()
+            );
+        }
+
+        for (field_sizes, group_separator, expected) in [
+            (
+                vec![2, 3],
+                ' ',
+                EncodeError::FieldSizeMismatch {
+                    expected: 6,
+                    found: 5,
+                },
+            ),
+            (
+                vec![2, 3, 2],
+                ' ',
+                EncodeError::FieldSizeMismatch {
+                    expected: 6,
+                    found: 7,
+                },
+            ),
+            (vec![6, 0], ' ', EncodeError::EmptyField { index: 1 }),
+            (
+                vec![usize::MAX, 7],
+                ' ',
+                EncodeError::FieldSizeMismatch {
+                    expected: 6,
+                    found: usize::MAX,
+                },
+            ),
+            (
+                vec![6],
+                '-',
+                EncodeError::AmbiguousGroupSeparator { separator: '-' },
+            ),
+            (
+                vec![6],
+                'x',
+                EncodeError::InvalidSeparator { separator: 'x' },
+            ),
+            (
+                vec![6],
+                '0',
+                EncodeError::InvalidSeparator { separator: '0' },
+            ),
+        ] {
+            assert_eq!(
+                Err(expected),
+                convert_to_pricklybird_fielded(data, &field_sizes, group_separator)
+            );
+        }
+        assert_eq!(
+            Ok(String::new()),
+            convert_to_pricklybird_fielded(&[], &[], ' ')
+        );
+    }
+
+    /// Check that the fallible encoder matches the infallible one.
+    #[test]
+    fn test_try_convert_to_pricklybird() {
+        assert_eq!(
+            Ok(convert_to_pricklybird(TEST_DATA)),
+            try_convert_to_pricklybird(&TEST_DATA)
+        );
+        assert_eq!(Ok(String::new()), try_convert_to_pricklybird(&[]));
+    }
+
+    /// Check that the CRC remainder is zero exactly for valid codes.
+    #[test]
+    fn test_crc_of_code() {
+        for code in [
+            "turf-port-rust-warn-void",
+            "flea-flux-full",
+            "blob-eggs-hair-king-meta-yell",
+            "acid-acid-acid-acid-acid-acid",
+            "zone-zone-zone-zone-zone-sand",
+        ] {
+            assert_eq!(Ok(0), crc_of_code(code), "Nonzero remainder for {code}.");
+        }
+        assert_eq!(Ok(0), crc_of_code(&convert_to_pricklybird(TEST_DATA)));
+        assert_eq!(
+            Ok(calculate_crc8(&[0x43, 0x42, calculate_crc8(&[0x42, 0x43])])),
+            crc_of_code("flux-flea-full")
+        );
+        assert!(matches!(
+            crc_of_code("flea-flax-full"),
+            Err(DecodeError::General(_))
+        ));
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 1 }),
+            crc_of_code("flea--full")
+        );
+    }
+
+    /// Check that wrapped lines fit the width and round trip when joined.
+    #[test]
+    fn test_convert_to_pricklybird_wrapped() {
+        let data = &TEST_DATA[..20];
+        let coded_words = convert_to_pricklybird(data);
+        for (max_width, words_per_line) in [(4, 1), (9, 2), (13, 2), (14, 3), (1000, 21)] {
+            let lines = convert_to_pricklybird_wrapped(data, max_width).unwrap();
+            assert!(
+                lines.iter().all(|line| line.len() <= max_width),
+                "Line exceeds width {max_width}."
+            );
+            assert!(
+                lines[..lines.len() - 1]
+                    .iter()
+                    .all(|line| line.split('-').count() == words_per_line),
+                "Lines for width {max_width} are not filled."
+            );
+            assert_eq!(coded_words, lines.join("-"));
+            assert_eq!(
+                data.to_vec(),
+                convert_from_pricklybird_flexible(&lines.join("\n")).unwrap()
+            );
+        }
+        assert_eq!(
+            vec!["flea-flux-full"],
+            convert_to_pricklybird_wrapped(&[0x42, 0x43], 14).unwrap()
+        );
+        for max_width in [0, 3] {
+            assert_eq!(
+                Err(EncodeError::LineTooNarrow {
+                    min: 4,
+                    found: max_width
+                }),
+                convert_to_pricklybird_wrapped(data, max_width)
+            );
+        }
+        assert!(
+            convert_to_pricklybird_wrapped(&[], 9).unwrap().is_empty(),
+            "Empty input produced lines."
+        );
+    }
+
+    /// Check that Unicode spaces are only accepted around the input by the standard decoder,
+    /// while the flexible decoder accepts them anywhere.
+    #[test]
+    fn test_unicode_spaces() {
+        let spaces = [
+            '\u{a0}', '\u{2009}', '\u{202f}', '\u{3000}', '\u{200b}', '\u{feff}',
+        ];
+        for space in spaces {
+            let padded = format!("{space}flea{space}flux{space}-{space}full{space}");
+            assert_eq!(
+                vec![0x42_u8, 0x43],
+                convert_from_pricklybird_flexible(&padded).unwrap(),
+                "Flexible decoder rejected U+{:04X}.",
+                u32::from(space)
+            );
+            assert!(
+                convert_from_pricklybird(&format!("flea{space}-flux-full")).is_err(),
+                "Standard decoder accepted U+{:04X} inside the input.",
+                u32::from(space)
+            );
+        }
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            convert_from_pricklybird("\u{a0}flea-flux-full\u{2009}").unwrap()
+        );
+    }
+
+    /// Check decoding into exactly sized and undersized buffers.
+    #[test]
+    fn test_decode_into_slice() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let mut exact_buffer = [0_u8; TEST_DATA_BYTES];
+        assert_eq!(
+            Ok(TEST_DATA_BYTES),
+            decode_into_slice(&coded_words, &mut exact_buffer)
+        );
+        assert_eq!(TEST_DATA, exact_buffer);
+
+        let mut small_buffer = [0_u8; TEST_DATA_BYTES - 1];
+        assert_eq!(
+            Err(DecodeError::BufferTooSmall {
+                needed: TEST_DATA_BYTES
+            }),
+            decode_into_slice(&coded_words, &mut small_buffer)
+        );
+
+        let mut buffer = [0_u8; 4];
+        assert!(
+            matches!(
+                decode_into_slice("flux-flea-full", &mut buffer),
+                Err(DecodeError::CRCError)
+            ),
+            "Slice decoder did not detect error caused by word swap."
+        );
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 1 }),
+            decode_into_slice("flea--full", &mut buffer)
+        );
+        assert!(decode_into_slice("flea", &mut buffer).is_err());
+        assert!(decode_into_slice("zzzz-king", &mut buffer).is_err());
+    }
+
+    /// Check that messy input is normalized to the canonical form.
+    #[test]
+    fn test_normalize() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(coded_words, normalize(&coded_words).unwrap());
+        let messy_words = format!("  {}\r\n", coded_words.to_uppercase().replace('-', " "));
+        assert_eq!(coded_words, normalize(&messy_words).unwrap());
+        assert!(
+            matches!(normalize("flux-flea-full"), Err(DecodeError::CRCError)),
+            "Normalization did not detect error caused by word swap."
+        );
+    }
+
+    /// Check that the encoder accepts different kinds of byte containers.
+    #[test]
+    fn test_generic_input_types() {
+        let expected = "flea-flux-full";
+        let array = [0x42_u8, 0x43];
+        let vector = vec![0x42_u8, 0x43];
+        assert_eq!(expected, convert_to_pricklybird(array));
+        assert_eq!(expected, convert_to_pricklybird(&array[..]));
+        assert_eq!(expected, convert_to_pricklybird(&vector));
+        assert_eq!(expected, convert_to_pricklybird(vector));
+        assert_eq!(expected, convert_to_pricklybird("BC"));
+        assert_eq!(expected, convert_to_pricklybird(String::from("BC")));
+        assert_eq!("", convert_to_pricklybird(Vec::<u8>::new()));
+        assert_eq!("", convert_to_pricklybird(""));
+    }
+
+    /// Check that word validation rejects each class of malformed word with the matching error.
+    #[test]
+    fn test_validate_word_bytes() {
+        assert_eq!(Ok(*b"flea"), validate_word_bytes(b"flea", 0));
+        assert_eq!(Ok(*b"FlEa"), validate_word_bytes(b"FlEa", 0));
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 3 }),
+            validate_word_bytes(b"", 3)
+        );
+        for invalid_word in [
+            &b"fle\x7f"[..],
+            b"fl\0a",
+            b"\x1bflea",
+            b"fle\xff",
+            b"\x80lea",
+            b"fl a",
+            b"fle1",
+        ] {
+            assert_eq!(
+                Err(DecodeError::NonAscii { index: 1 }),
+                validate_word_bytes(invalid_word, 1),
+                "Word validation did not reject {invalid_word:?}."
+            );
+        }
+        assert!(matches!(
+            validate_word_bytes(b"fleas", 0),
+            Err(DecodeError::General(_))
+        ));
+    }
+
+    /// Check that words containing Unicode lookalikes of ASCII letters are rejected.
+    #[test]
+    fn test_homoglyphs() {
+        let edge_cases = [
+            // Cyrillic small letter a
+            ("fle\u{430}-flux-full", 0, "Cyrillic a"),
+            // Cyrillic capital letter ES
+            ("flea-flux-\u{421}ash-full", 2, "Cyrillic C"),
+            // Fullwidth latin small letter f
+            ("flea-\u{ff46}lux-full", 1, "fullwidth f"),
+            // Kelvin sign, lowercases to an ASCII k
+            ("\u{212a}ing-full", 0, "Kelvin sign"),
+            // Latin small letter dotless i
+            ("k\u{131}ng-full", 0, "dotless i"),
+        ];
+        for (edge_case_input, expected_index, reason) in edge_cases {
+            assert_eq!(
+                Err(DecodeError::NonAscii {
+                    index: expected_index
+                }),
+                convert_from_pricklybird(edge_case_input),
+                "Converter did not reject {reason} in {edge_case_input}."
+            );
+        }
+        assert_eq!(
+            Err(DecodeError::NonAscii { index: 1 }),
+            convert_from_pricklybird_flexible("flea \u{ff46}lux full"),
+            "Flexible converter did not reject fullwidth f."
+        );
+    }
+
+    /// Check that the string slice words match the byte array words.
+    #[test]
+    fn test_word_strs() {
+        let word_strs = bytes_to_word_strs(&TEST_DATA);
+        let words = bytes_to_words(&TEST_DATA);
+        assert_eq!(TEST_DATA.len(), word_strs.len());
+        for (word_str, word) in word_strs.iter().zip(words) {
+            assert_eq!(word_str.as_bytes(), word);
+        }
+        assert_eq!(TEST_DATA.to_vec(), words_to_bytes(&word_strs).unwrap());
+    }
+
+    /// Check that the `Codec` implementation matches the free functions.
+    #[test]
+    fn test_codec() {
+        let codec: Box<dyn Codec> = Box::new(Pricklybird);
+        let coded_words = codec.encode(&TEST_DATA);
+        assert_eq!(convert_to_pricklybird(TEST_DATA), coded_words);
+        assert_eq!(TEST_DATA.to_vec(), codec.decode(&coded_words).unwrap());
+        assert!(
+            matches!(codec.decode("flux-flea-full"), Err(DecodeError::CRCError)),
+            "Codec did not detect error caused by word swap."
+        );
+    }
+
+    /// Check that `word_for_byte` can be evaluated at compile time and matches the wordlist.
+    #[test]
+    fn test_word_for_byte() {
+        const FLUX: [u8; 4] = constants::word_for_byte(0x43);
+        assert_eq!(*b"flux", FLUX);
+        for byte in 0..=u8::MAX {
+            assert_eq!(
+                WORDLIST[byte as usize].as_bytes(),
+                constants::word_for_byte(byte)
+            );
+        }
+    }
+
+    /// Check that `word_hash` stays within the bounds of `HASH_TABLE` for any pair of bytes.
+    #[test]
+    fn test_word_hash_bounds() {
+        for first in 0..=u8::MAX {
+            for last in 0..=u8::MAX {
+                let table_index = constants::word_hash(first, last);
+                assert!(
+                    table_index < constants::HASH_TABLE_SIZE,
+                    "Hash of {first:#x} and {last:#x} is out of bounds."
+                );
+            }
+        }
+    }
+
+    /// Check the error offsets for every kind of invalid word.
+    #[test]
+    fn test_first_error_position() {
+        assert_eq!(
+            None,
+            first_error_position(&convert_to_pricklybird(TEST_DATA))
+        );
+        assert_eq!(None, first_error_position(" FLEA-flux-full\n"));
+        // Prefixes are not errors as long as the last word is complete.
+        assert_eq!(None, first_error_position("flea"));
+        assert_eq!(Some(2), first_error_position("fl"));
+        assert_eq!(Some(0), first_error_position(""));
+
+        let cases = [
+            ("  flea-fl\u{fc}x-full", 9),
+            ("flea-flux-full-", 15),
+            ("flea--full", 5),
+            ("flea-fluxx-full", 9),
+            ("flea-flu", 8),
+            ("flea-flux-fxxl", 10),
+            ("flea flux full", 4),
+            ("\tflea-flux-fu7l", 13),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                Some(expected),
+                first_error_position(input),
+                "Wrong error position for '{}'.",
+                input.escape_default()
+            );
+            assert!(convert_from_pricklybird(input).is_err());
+        }
+    }
+
+    /// Check that only complete codes are reported as complete while typing.
+    #[test]
+    fn test_is_complete() {
+        let code = "turf-port-rust-warn-void";
+        for end in 0..code.len() {
+            let partial = &code[..end];
+            assert!(
+                !is_complete(partial),
+                "Prefix '{partial}' reported as complete."
+            );
+        }
+        assert!(is_complete(code));
+        assert!(is_complete(" turf-port-rust-warn-void\n"));
+        assert!(!is_complete("turf-port-rust-void-warn"));
+        assert!(!is_complete("turf-port-rust-warn-void-"));
+        assert_eq!(
+            Ok(()),
+            verify_pricklybird(&convert_to_pricklybird(TEST_DATA))
+        );
+    }
+
+    /// Check that words are joined with the given separator and no CRC is attached.
+    #[test]
+    fn test_bytes_to_pricklybird_string() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let (payload_words, _) = coded_words.rsplit_once('-').unwrap();
+        assert_eq!(payload_words, bytes_to_pricklybird_string(&TEST_DATA, '-'));
+        assert_eq!(
+            "flea\u{b7}flux",
+            bytes_to_pricklybird_string(&[0x42, 0x43], '\u{b7}')
+        );
+        assert_eq!("flea", bytes_to_pricklybird_string(&[0x42], '-'));
+        assert_eq!("", bytes_to_pricklybird_string(&[], '-'));
+    }
+
+    /// Check that padded codes have a fixed length and decode to the padded data.
+    #[test]
+    fn test_convert_to_pricklybird_padded() {
+        for data_len in [0, 1, 15, 16] {
+            let code = convert_to_pricklybird_padded(&TEST_DATA[..data_len], 16, 0xFF).unwrap();
+            assert_eq!(encoded_str_len(16), code.len());
+            let mut expected = TEST_DATA[..data_len].to_vec();
+            expected.resize(16, 0xFF);
+            assert_eq!(expected, convert_from_pricklybird(&code).unwrap());
+        }
+        assert_eq!("", convert_to_pricklybird_padded(&[], 0, 0x00).unwrap());
+        assert_eq!(
+            Err(EncodeError::TooLong { max: 16, found: 17 }),
+            convert_to_pricklybird_padded(&TEST_DATA[..17], 16, 0x00)
+        );
+        assert_eq!(
+            "Data too long, found 17 bytes but at most 16 are allowed.",
+            EncodeError::TooLong { max: 16, found: 17 }.to_string()
+        );
+    }
+
+    /// Check that encoding into and decoding from byte buffers matches the string functions.
+    #[test]
+    fn test_byte_buffers() {
+        let mut buf = b"prefix ".to_vec();
+        convert_to_pricklybird_buf(&TEST_DATA, &mut buf);
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(coded_words.as_bytes(), &buf[7..]);
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_bytes(&buf[7..]).unwrap()
+        );
+
+        let mut empty_buf = Vec::new();
+        convert_to_pricklybird_buf(&[], &mut empty_buf);
+        assert!(empty_buf.is_empty());
+
+        assert_eq!(
+            Err(DecodeError::General("Input is not valid UTF-8.".into())),
+            convert_from_pricklybird_bytes(b"flea-flu\xff-full")
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_bytes(b"flux-flea-full")
+        );
+    }
+
+    /// Check that the CRC byte returned with the code is the CRC that was appended.
+    #[test]
+    fn test_convert_to_pricklybird_with_crc_byte() {
+        let (code, crc) = convert_to_pricklybird_with_crc_byte(&TEST_DATA);
+        assert_eq!(convert_to_pricklybird(TEST_DATA), code);
+        assert_eq!(calculate_crc8(&TEST_DATA), crc);
+        assert_eq!(
+            (String::new(), 0),
+            convert_to_pricklybird_with_crc_byte(&[])
+        );
+    }
+
+    /// Check that vectored encoding matches encoding the concatenated data.
+    #[test]
+    fn test_convert_to_pricklybird_vectored() {
+        assert_eq!(
+            convert_to_pricklybird([0x42_u8, 0x43]),
+            convert_to_pricklybird_vectored(&[&[0x42], &[0x43]])
+        );
+        let (header, body) = TEST_DATA.split_at(7);
+        assert_eq!(
+            convert_to_pricklybird(TEST_DATA),
+            convert_to_pricklybird_vectored(&[header, &[], body])
+        );
+        assert_eq!(
+            convert_to_pricklybird(TEST_DATA),
+            convert_to_pricklybird_vectored(&[&TEST_DATA])
+        );
+        assert_eq!("", convert_to_pricklybird_vectored(&[]));
+        assert_eq!("", convert_to_pricklybird_vectored(&[&[], &[]]));
+    }
+
+    /// Check that the ASCII encoder returns the bytes of the string encoder.
+    #[test]
+    fn test_convert_to_pricklybird_ascii() {
+        let code = convert_to_pricklybird_ascii(&TEST_DATA);
+        assert_eq!(convert_to_pricklybird(TEST_DATA).into_bytes(), code);
+        assert!(code.is_ascii(), "Encoded bytes are not ASCII.");
+        assert!(
+            convert_to_pricklybird_ascii(&[]).is_empty(),
+            "Empty input produced output."
+        );
+    }
+
+    /// Check that the stream functions match the string functions and report decode errors.
+    #[test]
+    fn test_streams() {
+        let mut encoded = Vec::new();
+        encode_stream(&TEST_DATA[..], &mut encoded).unwrap();
+        assert_eq!(
+            convert_to_pricklybird(TEST_DATA).as_bytes(),
+            encoded.as_slice()
+        );
+
+        let mut decoded = Vec::new();
+        decode_stream(encoded.as_slice(), &mut decoded).unwrap();
+        assert_eq!(TEST_DATA.to_vec(), decoded);
+
+        let mut empty = Vec::new();
+        encode_stream(&[][..], &mut empty).unwrap();
+        assert!(empty.is_empty(), "Empty input produced output.");
+
+        let mut failed = Vec::new();
+        let error = decode_stream(&b"flux-flea-full"[..], &mut failed).unwrap_err();
+        assert!(
+            matches!(error, StreamError::Decode(DecodeError::CRCError)),
+            "The decode error was not reported as such."
+        );
+        assert!(failed.is_empty(), "Output was written for invalid input.");
+
+        let utf8_error = decode_stream(&[0x66_u8, 0xFF][..], Vec::new()).unwrap_err();
+        assert!(
+            matches!(utf8_error, StreamError::Decode(DecodeError::General(_))),
+            "Invalid UTF-8 was not reported as a decode error."
+        );
+        let mut closed = [0_u8; 1];
+        let write_error = decode_stream(&b"flea-flux-full"[..], &mut closed[..]).unwrap_err();
+        assert!(
+            matches!(write_error, StreamError::Io(_)),
+            "Failing to write the output was not reported as an IO error."
+        );
+    }
+
+    /// Check that the CRC check can be skipped while still removing the CRC word.
+    #[test]
+    fn test_convert_from_pricklybird_opts() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_opts(&coded_words, true).unwrap()
+        );
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_opts(&coded_words, false).unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_opts("flux-flea-full", true)
+        );
+        assert_eq!(
+            vec![0x43_u8, 0x42],
+            convert_from_pricklybird_opts("flux-flea-full", false).unwrap()
+        );
+        // Other errors are still reported without the CRC check.
+        assert!(convert_from_pricklybird_opts("flux-flax-full", false).is_err());
+        assert!(convert_from_pricklybird_opts("flux", false).is_err());
+    }
+
+    /// Check that codes differing only in formatting compare equal.
+    #[test]
+    fn test_codes_equal() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let reformatted_words = format!("\t{}\r\n", coded_words.to_uppercase().replace('-', " "));
+        assert!(codes_equal(&coded_words, &reformatted_words).unwrap());
+        assert!(codes_equal("flea-flux-full", "Flea  FLUX_full").unwrap());
+        assert!(!codes_equal("flea-flux-full", "flux-flea-call").unwrap());
+        assert!(!codes_equal("flea-flux-full", "flea-flux-flea-kale").unwrap());
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            codes_equal("flea-flux-full", "flux-flea-full")
+        );
+        assert!(codes_equal("", "flea-flux-full").is_err());
+    }
+
+    /// Check the layout of `HexDump` for full, partial and empty lines.
+    #[test]
+    fn test_hex_dump() {
+        let data: Vec<u8> = (0x3A..0x5C).chain([0x00, 0x7F, 0xFF]).collect();
+        let expected = "\
+00000000  3a 3b 3c 3d 3e 3f 40 41  42 43 44 45 46 47 48 49  |:;<=>?@ABCDEFGHI|
+00000010  4a 4b 4c 4d 4e 4f 50 51  52 53 54 55 56 57 58 59  |JKLMNOPQRSTUVWXY|
+00000020  5a 5b 00 7f ff                                    |Z[...|
+";
+        assert_eq!(expected, HexDump(&data).to_string());
+        assert_eq!("", HexDump(&[]).to_string());
+    }
+
+    /// Check that chunks are verified independently.
+    #[test]
+    fn test_chunked() {
+        let chunks = encode_chunked(&TEST_DATA, 100);
+        assert_eq!(TEST_DATA_BYTES.div_ceil(100), chunks.len());
+        let mut chunk_strs: Vec<&str> = chunks.iter().map(String::as_str).collect();
+        assert_eq!(TEST_DATA.to_vec(), decode_chunked(&chunk_strs).unwrap());
+        assert!(encode_chunked(&[], 100).is_empty());
+        assert_eq!(Vec::<u8>::new(), decode_chunked(&[]).unwrap());
+
+        // Swap two words in the third chunk.
+        let mut corrupted_words: Vec<&str> = chunk_strs[2].split('-').collect();
+        corrupted_words.swap(0, 1);
+        let corrupted_chunk = corrupted_words.join("-");
+        chunk_strs[2] = &corrupted_chunk;
+
+        assert_eq!(Err(DecodeError::CRCError), decode_chunked(&chunk_strs));
+        for (index, chunk) in chunk_strs.iter().enumerate() {
+            let chunk_result = convert_from_pricklybird(chunk);
+            if index == 2 {
+                assert_eq!(Err(DecodeError::CRCError), chunk_result);
+            } else {
+                assert_eq!(
+                    TEST_DATA.chunks(100).nth(index).unwrap(),
+                    chunk_result.unwrap(),
+                    "Chunk {index} was affected by the corrupted chunk."
+                );
+            }
+        }
+    }
+
+    /// Check that any combination of trailing line endings and spaces is ignored.
+    #[test]
+    fn test_trailing_newline() {
+        for suffix in [
+            "\n", "\r\n", "\r", " ", " \r\n", "\r\n\r\n", "\n\r", "\t \n ",
+        ] {
+            let input = format!("flea-flux-full{suffix}");
+            assert_eq!(
+                vec![0x42_u8, 0x43],
+                convert_from_pricklybird(&input).unwrap(),
+                "Decoding failed with trailing '{}'.",
+                suffix.escape_default()
+            );
+        }
+    }
+
+    /// Check that codes are decoded with and without version prefix.
+    #[test]
+    fn test_convert_from_pricklybird_any() {
+        let bare_words = convert_to_pricklybird(TEST_DATA);
+        let versioned_words = convert_to_pricklybird_versioned(&TEST_DATA);
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_any(&bare_words).unwrap()
+        );
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_any(&versioned_words).unwrap()
+        );
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            convert_from_pricklybird_any(" v1:flea-flux-full\n").unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::VersionMismatch {
+                found: "v0".into(),
+                expected: PRICKLYBIRD_VERSION
+            }),
+            convert_from_pricklybird_any("v0:flea-flux-full")
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_any("v1:flux-flea-full")
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_any("flux-flea-full")
+        );
+    }
+
+    /// Check the boundaries of the Hamming distance table using the linearity of the CRC.
+    ///
+    /// Since the initial value is zero, an error pattern goes undetected
+    /// exactly if its own CRC remainder is zero.
+    #[test]
+    fn test_crc_guarantees() {
+        /// Check whether flipping the given bits of a codeword with `codeword_len` bytes is detected.
+        fn detected(codeword_len: usize, bits: &[usize]) -> bool {
+            let mut error = vec![0_u8; codeword_len];
+            for &bit in bits {
+                error[bit / 8] ^= 0x80 >> (bit % 8);
+            }
+            calculate_crc8(&error) != 0
+        }
+
+        assert_eq!(4, crc_guarantees(0).hamming_distance);
+        assert_eq!(4, crc_guarantees(1).hamming_distance);
+        assert_eq!(3, crc_guarantees(2).hamming_distance);
+        assert_eq!(3, crc_guarantees(30).hamming_distance);
+        assert_eq!(2, crc_guarantees(31).hamming_distance);
+        assert_eq!(2, crc_guarantees(usize::MAX).hamming_distance);
+
+        // Single payload byte: all three bit errors are detected.
+        for first in 0..16 {
+            for second in first + 1..16 {
+                for third in second + 1..16 {
+                    assert!(detected(2, &[first, second, third]));
+                }
+            }
+        }
+        // 30 payload bytes: all two bit errors are detected, but not all three bit errors.
+        let bits = 31 * 8;
+        for first in 0..bits {
+            for second in first + 1..bits {
+                assert!(detected(31, &[first, second]));
+            }
+        }
+        assert!(
+            (0..24).any(|first| (first + 1..24)
+                .any(|second| (second + 1..24).any(|third| !detected(3, &[first, second, third]))))
+        );
+        // 31 payload bytes: two bits 255 positions apart are not detected.
+        assert!(!detected(32, &[0, 255]));
+    }
+
+    /// Check which characters are accepted as separators.
+    #[test]
+    fn test_is_valid_separator() {
+        for letter in ['a', 'z', 'A', 'Z', 'f'] {
+            assert!(!is_valid_separator(letter), "Letter {letter} was accepted.");
+        }
+        for valid in ['-', '_', '.', '/', '+', '~', ' ', '\u{b7}'] {
+            assert!(
+                is_valid_separator(valid),
+                "Separator '{}' was rejected.",
+                valid.escape_default()
+            );
+        }
+        for invalid in [
+            '0',
+            '9',
+            '\t',
+            '\n',
+            '\r',
+            '\0',
+            '\u{a0}',
+            VERSION_SEPARATOR,
+        ] {
+            assert!(
+                !is_valid_separator(invalid),
+                "Separator '{}' was accepted.",
+                invalid.escape_default()
+            );
+        }
+        assert!(is_valid_separator(URL_SEPARATOR));
+    }
+
+    /// Check the round trip of URL safe codes.
+    #[test]
+    fn test_url() {
+        assert!(
+            WORDLIST.iter().all(|word| !word.contains(URL_SEPARATOR)),
+            "The URL separator appears in the wordlist."
+        );
+        assert!(URL_SEPARATOR.is_ascii_punctuation());
+        let url_words = convert_to_pricklybird_url(&TEST_DATA);
+        assert_eq!(
+            convert_to_pricklybird(TEST_DATA).replace('-', "_"),
+            url_words
+        );
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_url(&url_words).unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_url("flux_flea_full")
+        );
+        assert!(convert_from_pricklybird_url("flea-flux-full").is_err());
+        assert_eq!("", convert_to_pricklybird_url(&[]));
+    }
+
+    /// Check that QR codes only use the alphanumeric character set and round trip.
+    #[test]
+    fn test_qr_alphanumeric_code() {
+        /// Characters QR codes can encode in alphanumeric mode.
+        const QR_ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+        let qr_words = qr_alphanumeric_code(&TEST_DATA);
+        assert!(
+            qr_words.chars().all(|c| QR_ALPHANUMERIC.contains(c)),
+            "QR code contains characters outside the alphanumeric set."
+        );
+        assert_eq!(
+            convert_to_pricklybird(TEST_DATA)
+                .to_ascii_uppercase()
+                .replace('-', " "),
+            qr_words
+        );
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            decode_qr_alphanumeric_code(&qr_words).unwrap()
+        );
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            decode_qr_alphanumeric_code("flea FLUX full\n").unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            decode_qr_alphanumeric_code("FLUX FLEA FULL")
+        );
+        assert!(decode_qr_alphanumeric_code("FLEA  FLUX FULL").is_err());
+        assert_eq!("", qr_alphanumeric_code(&[]));
+    }
+
+    /// Check the round trip of integers and that the payload length is enforced.
+    #[test]
+    fn test_integers() {
+        for value in [0, 1, 0x4243, u16::MAX] {
+            assert_eq!(value, decode_u16(&encode_u16(value)).unwrap());
+        }
+        for value in [0, 1, 0xDEAD_BEEF, u32::MAX] {
+            assert_eq!(value, decode_u32(&encode_u32(value)).unwrap());
+        }
+        for value in [0, 1, 0x0123_4567_89AB_CDEF, u64::MAX] {
+            assert_eq!(value, decode_u64(&encode_u64(value)).unwrap());
+        }
+        assert_eq!(
+            convert_to_pricklybird([0, 0, 0, 0, 0, 0, 0, 1]),
+            encode_u64(1),
+            "Integers must be encoded big-endian with a fixed width."
+        );
+
+        assert!(matches!(
+            decode_u32(&encode_u16(0x4243)),
+            Err(DecodeError::General(_))
+        ));
+        assert!(matches!(
+            decode_u16(&encode_u64(0x4243)),
+            Err(DecodeError::General(_))
+        ));
+        assert_eq!(Err(DecodeError::CRCError), decode_u16("flux-flea-full"));
+    }
+
+    /// Check that tokens are split like the decoder splits its input.
+    #[test]
+    fn test_tokens() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            decode_tokens(tokens(&format!("\t{coded_words} \n"))).unwrap()
+        );
+        assert_eq!(TEST_DATA_BYTES + 1, tokens(&coded_words).count());
+        assert_eq!(vec![""], tokens("  ").collect::<Vec<_>>());
+        assert_eq!(vec!["", "flea", ""], tokens("-flea-").collect::<Vec<_>>());
+    }
+
+    /// Check that the CRC word is recomputed from the payload words.
+    #[test]
+    fn test_refresh_crc() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(coded_words, refresh_crc(&coded_words).unwrap());
+        let (payload_words, _) = coded_words.rsplit_once('-').unwrap();
+        assert_eq!(
+            coded_words,
+            refresh_crc(&format!("{payload_words}-acid")).unwrap()
+        );
+        assert_eq!("flea-flux-full", refresh_crc(" FLEA-flux-zone\n").unwrap());
+        assert!(matches!(
+            refresh_crc("flea-flux-qqqq"),
+            Err(DecodeError::General(_))
+        ));
+        assert!(matches!(refresh_crc("flea"), Err(DecodeError::General(_))));
+    }
+
+    /// Check that CRC mismatches are reported with both CRC values instead of failing.
+    #[test]
+    fn test_convert_from_pricklybird_tolerant() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let result = convert_from_pricklybird_tolerant(&coded_words).unwrap();
+        assert_eq!(TEST_DATA.to_vec(), result.payload);
+        assert!(result.crc_ok, "Valid CRC reported as mismatch.");
+        assert_eq!(calculate_crc8(&TEST_DATA), result.expected_crc);
+        assert_eq!(result.expected_crc, result.found_crc);
+
+        let mismatch = convert_from_pricklybird_tolerant("flux-flea-full").unwrap();
+        assert_eq!(
+            TolerantDecodeResult {
+                payload: vec![0x43, 0x42],
+                crc_ok: false,
+                expected_crc: calculate_crc8(&[0x43, 0x42]),
+                found_crc: calculate_crc8(&[0x42, 0x43]),
+            },
+            mismatch
+        );
+        assert_eq!(
+            convert_from_pricklybird_checked("flux-flea-full")
+                .unwrap()
+                .crc_ok,
+            mismatch.crc_ok
+        );
+
+        assert!(matches!(
+            convert_from_pricklybird_tolerant("flea-flax-full"),
+            Err(DecodeError::General(_))
+        ));
+        assert!(matches!(
+            convert_from_pricklybird_tolerant("flea"),
+            Err(DecodeError::General(_))
+        ));
+    }
+
+    /// Check that codes with and without CRC word are both decoded.
+    #[test]
+    fn test_convert_from_pricklybird_optional_crc() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(
+            (TEST_DATA.to_vec(), true),
+            convert_from_pricklybird_optional_crc(&coded_words).unwrap()
+        );
+        let legacy_words = bytes_to_pricklybird_string(&TEST_DATA, '-');
+        assert_eq!(
+            (TEST_DATA.to_vec(), false),
+            convert_from_pricklybird_optional_crc(&legacy_words).unwrap()
+        );
+        // The CRC of a single zero byte is zero, but a single word is always payload.
+        assert_eq!(
+            (vec![0x00_u8], false),
+            convert_from_pricklybird_optional_crc("acid").unwrap()
+        );
+        // Ambiguous legacy code whose last byte equals the CRC of the preceding bytes.
+        assert_eq!(
+            (vec![0x42_u8, 0x43], true),
+            convert_from_pricklybird_optional_crc(&bytes_to_pricklybird_string(
+                &[0x42, 0x43, calculate_crc8(&[0x42, 0x43])],
+                '-'
+            ))
+            .unwrap()
+        );
+        assert!(matches!(
+            convert_from_pricklybird_optional_crc(" \n"),
+            Err(DecodeError::General(_))
         ));
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 1 }),
+            convert_from_pricklybird_optional_crc("flea--flux")
+        );
     }
 
-    let mut data = words_to_bytes(&word_vec)?;
-    if calculate_crc8(&data) != 0 {
-        return Err(DecodeError::CRCError);
-    }
-    // Remove CRC
-    let _ = data.pop();
-    Ok(data)
-}
+    /// Check that the verbose decoder reports every word including the flagged CRC word.
+    #[test]
+    fn test_decode_verbose() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let decoded = decode_verbose(&coded_words).unwrap();
+        assert_eq!(TEST_DATA_BYTES + 1, decoded.len());
+        for (decoded_word, word) in decoded.iter().zip(coded_words.split('-')) {
+            assert_eq!(word, decoded_word.word);
+            assert_eq!(WORDLIST[decoded_word.byte as usize], word);
+        }
+        let payload: Vec<u8> = decoded
+            .iter()
+            .filter(|decoded_word| !decoded_word.is_crc)
+            .map(|decoded_word| decoded_word.byte)
+            .collect();
+        assert_eq!(TEST_DATA.to_vec(), payload);
+        assert_eq!(1, decoded.iter().filter(|word| word.is_crc).count());
 
-/// Test the conversion from and to pricklybird.
-#[cfg(test)]
-mod pricklybird_tests {
-    use super::*;
-    /// Seed used to generate test data using the PRNG implemented in `generate_test_data`.
-    const TEST_DATA_SEED: u128 = 1;
-    /// How many byes of test data to use for conversion tests.
-    const TEST_DATA_BYTES: usize = 4096;
-    /// Pseudorandom data used to test conversion functions.
-    const TEST_DATA: [u8; TEST_DATA_BYTES] = generate_test_data(TEST_DATA_SEED);
-
-    /// Generates pseudorandom test data using the Lehmer64 LCG.
-    #[allow(clippy::cast_possible_truncation)]
-    const fn generate_test_data(seed: u128) -> [u8; TEST_DATA_BYTES] {
-        const MULTIPLIER: u128 = 0xDA942042E4DD58B5;
-        const WARMUP_ITERATIONS: usize = 128;
-        let mut state = seed;
-        // Mix up the state a little to compensate for potentialy small seed.
-        let mut i: usize = 0;
-        while i < WARMUP_ITERATIONS {
-            state = state.wrapping_mul(MULTIPLIER);
-            i += 1;
-        }
-
-        let mut j: usize = 0;
-        let mut result = [0_u8; TEST_DATA_BYTES];
-        while j < TEST_DATA_BYTES {
-            state = state.wrapping_mul(MULTIPLIER);
-            let random_val = (state >> 64) as u64;
-            result[j] = random_val as u8;
-            result[j + 1] = (random_val >> 8) as u8;
-            result[j + 2] = (random_val >> 16) as u8;
-            result[j + 3] = (random_val >> 24) as u8;
-            result[j + 4] = (random_val >> 32) as u8;
-            result[j + 5] = (random_val >> 40) as u8;
-            result[j + 6] = (random_val >> 48) as u8;
-            result[j + 7] = (random_val >> 56) as u8;
-            j += 8;
-        }
-        result
+        assert_eq!(Err(DecodeError::CRCError), decode_verbose("flux-flea-full"));
+        assert!(decode_verbose("").is_err(), "Empty input was accepted.");
     }
 
-    /// Test the standard vectors supplied with the specification.
+    /// Check the round trip of labeled codes, including labels containing dashes.
     #[test]
-    fn test_vectors() {
-        let test_vectors = vec![
-            (vec![0xDE_u8, 0xAD, 0xBE, 0xEF], "turf-port-rust-warn-void"),
-            (vec![0x42_u8, 0x43], "flea-flux-full"),
-            (
-                vec![0x12_u8, 0x34, 0x56, 0x78, 0x90],
-                "blob-eggs-hair-king-meta-yell",
-            ),
-            (vec![0_u8; 5], "acid-acid-acid-acid-acid-acid"),
-            (vec![0xFF_u8; 5], "zone-zone-zone-zone-zone-sand"),
-        ];
-
-        for (data, words) in test_vectors {
-            // Test converting bytes to pricklybird.
+    fn test_labeled() {
+        for label in ["BACKUP", "my-backup", "KEY-2", "flea"] {
+            let labeled_words = convert_to_pricklybird_labeled(&TEST_DATA, label);
             assert_eq!(
-                words,
-                convert_to_pricklybird(&data),
-                "Failed to convert {:?} test vector to pricklybird.",
-                data
+                TEST_DATA.to_vec(),
+                convert_from_pricklybird_labeled(&labeled_words, label).unwrap(),
+                "Labeled code with label {label} did not round trip."
+            );
+        }
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            convert_from_pricklybird_labeled(" Backup-flea-flux-full\n", "BACKUP").unwrap()
+        );
+        for unlabeled in [
+            "flea-flux-full",
+            "BACKUPflea-flux-full",
+            "BACK-flea-flux-full",
+            "",
+        ] {
+            assert!(
+                matches!(
+                    convert_from_pricklybird_labeled(unlabeled, "BACKUP"),
+                    Err(DecodeError::General(_))
+                ),
+                "Missing label in {unlabeled} was not detected."
             );
+        }
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_labeled("BACKUP-flux-flea-full", "BACKUP")
+        );
+    }
 
-            // Test converting pricklybird to bytes.
-            assert_eq!(
-                data,
-                convert_from_pricklybird(words).unwrap(),
-                "Failed to convert {} test vector to bytes.",
-                words
+    /// Check that `crc_word` returns the last word of the encoded data.
+    #[test]
+    fn test_crc_word() {
+        assert_eq!("full", crc_word(&[0x42, 0x43]));
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(Some(crc_word(&TEST_DATA)), coded_words.rsplit('-').next());
+    }
+
+    /// Check that the separately returned CRC word matches the joined encoding.
+    #[test]
+    fn test_bytes_to_words_with_crc() {
+        let (words, crc) = bytes_to_words_with_crc(&TEST_DATA);
+        assert_eq!(bytes_to_words(&TEST_DATA), words);
+        assert_eq!(crc_word(&TEST_DATA).as_bytes(), crc);
+
+        let (empty_words, empty_crc) = bytes_to_words_with_crc(&[]);
+        assert!(empty_words.is_empty(), "Empty input produced words.");
+        assert_eq!(BYTE_WORDLIST[0], empty_crc);
+    }
+
+    /// Check the classification of every error variant.
+    #[test]
+    fn test_error_classification() {
+        let structural_errors = [
+            DecodeError::General("Input is empty.".into()),
+            DecodeError::EmptyWord { index: 0 },
+            DecodeError::NonAscii { index: 0 },
+            DecodeError::VersionMismatch {
+                found: "v0".into(),
+                expected: PRICKLYBIRD_VERSION,
+            },
+            DecodeError::TooLong { max: 1, found: 2 },
+            DecodeError::TooShort { min: 2, found: 1 },
+        ];
+        for error in structural_errors {
+            assert!(error.is_structural_error(), "{error} is not structural.");
+            assert!(
+                !error.is_integrity_error(),
+                "{error} is an integrity error."
             );
         }
+        assert!(DecodeError::CRCError.is_integrity_error());
+        assert!(!DecodeError::CRCError.is_structural_error());
+        for error in [
+            DecodeError::BufferTooSmall { needed: 1 },
+            DecodeError::InvalidSeparator { separator: 'a' },
+        ] {
+            assert!(
+                !error.is_integrity_error(),
+                "{error} is an integrity error."
+            );
+            assert!(!error.is_structural_error(), "{error} is structural.");
+        }
     }
 
-    /// Test conversion to and from pricklybird on pseudorandom test data.
+    /// Check that inputs are rejected exactly when they exceed the word limit.
     #[test]
-    fn test_simple_conversion() {
-        let coded_words = convert_to_pricklybird(&TEST_DATA);
-        let decoded_data = convert_from_pricklybird(&coded_words).unwrap();
+    fn test_convert_from_pricklybird_limited() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let total_words = TEST_DATA_BYTES + 1;
         assert_eq!(
             TEST_DATA.to_vec(),
-            decoded_data,
-            "Converter did not correctly encode or decode data."
+            convert_from_pricklybird_limited(&coded_words, total_words).unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::TooLong {
+                max: total_words - 1,
+                found: total_words
+            }),
+            convert_from_pricklybird_limited(&coded_words, total_words - 1)
+        );
+        // Structural errors within the limit are still reported.
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_limited("flux-flea-full", 3)
         );
+        assert!(convert_from_pricklybird_limited("", 0).is_err());
     }
 
-    /// Test that pricklybird input containing mixed case is properly decoded.
+    /// Check that payloads are rejected exactly when they are shorter than the minimum.
     #[test]
-    fn test_uppercase() {
+    fn test_convert_from_pricklybird_min() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
         assert_eq!(
-            vec![0xDE_u8, 0xAD, 0xBE, 0xEF],
-            convert_from_pricklybird("TUrF-Port-RUST-warn-vOid").unwrap(),
-            "Converter did not correctly decode uppercase data."
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_min(&coded_words, TEST_DATA_BYTES).unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::TooShort {
+                min: TEST_DATA_BYTES + 1,
+                found: TEST_DATA_BYTES
+            }),
+            convert_from_pricklybird_min(&coded_words, TEST_DATA_BYTES + 1)
+        );
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            convert_from_pricklybird_min("flea-flux-full", 0).unwrap()
+        );
+        // Decoding errors take precedence over the length check.
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_min("flux-flea-full", 4)
+        );
+        assert_eq!(
+            Err(DecodeError::General("Input is empty.".into())),
+            convert_from_pricklybird_min("", 0)
         );
     }
 
-    /// Test that replacing a pricklybird word is detected using the CRC-8.
+    /// Check that positioned decoding yields every word with its position, including errors.
     #[test]
-    fn test_error_detection_bit_flip() {
-        let coded_words = convert_to_pricklybird(&TEST_DATA);
-        let mut corrupt_data = TEST_DATA;
-        corrupt_data[0] ^= 1;
-        let incorrect_word =
-            String::from_utf8(bytes_to_words(&corrupt_data[0..1])[0].to_vec()).unwrap();
-        let incorrect_coded_words = format!("{}{}", &incorrect_word[..4], &coded_words[4..]);
-        assert!(
-            convert_from_pricklybird(&incorrect_coded_words).is_err(),
-            "Converter did not detect error in corrupted input."
+    fn test_decode_positioned() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let expected: Vec<(usize, Result<u8>)> = TEST_DATA
+            .iter()
+            .chain([calculate_crc8(&TEST_DATA)].iter())
+            .map(|&byte| Ok(byte))
+            .enumerate()
+            .collect();
+        assert_eq!(
+            expected,
+            decode_positioned(&coded_words).collect::<Vec<_>>()
+        );
+
+        let with_errors: Vec<(usize, Result<u8>)> =
+            decode_positioned(" flea-FLAX--fl\u{e4}x-full").collect();
+        assert_eq!(
+            vec![
+                (0, Ok(0x42)),
+                (
+                    1,
+                    Err(DecodeError::General(
+                        "Invalid word detected in input.".into()
+                    ))
+                ),
+                (2, Err(DecodeError::EmptyWord { index: 2 })),
+                (3, Err(DecodeError::NonAscii { index: 3 })),
+                (4, Ok(0x46)),
+            ],
+            with_errors
+        );
+        assert_eq!(
+            vec![(0, Err(DecodeError::EmptyWord { index: 0 }))],
+            decode_positioned("").collect::<Vec<_>>()
         );
     }
 
-    /// Check that swapping two adjacent words is detected using the CRC-8.
+    /// Check that readouts contain the canonical words and invalid codes are rejected.
     #[test]
-    fn test_error_detection_adjacent_swap() {
-        let coded_words = convert_to_pricklybird(&TEST_DATA);
-        let mut word_vec: Vec<&str> = coded_words.split('-').collect();
-        word_vec.swap(0, 1);
-        let swapped_coded_words = word_vec.join("-");
+    fn test_phonetic_readout() {
+        assert_eq!(
+            "turf, port, rust, warn, void",
+            phonetic_readout("turf-port-rust-warn-void", WordCase::Lower).unwrap()
+        );
+        assert_eq!(
+            "Flea, Flux, Full",
+            phonetic_readout("\tFLEA-flux-Full\n", WordCase::Title).unwrap()
+        );
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let readout = phonetic_readout(&coded_words, WordCase::Upper).unwrap();
+        assert_eq!(
+            coded_words.to_uppercase(),
+            readout.replace(READOUT_SEPARATOR, "-")
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            phonetic_readout("flux-flea-full", WordCase::Lower)
+        );
         assert!(
-            matches!(
-                convert_from_pricklybird(&swapped_coded_words),
-                Err(DecodeError::CRCError)
-            ),
-            "Converter did not detect error caused by word swap."
+            phonetic_readout("flea-flax-full", WordCase::Lower).is_err(),
+            "Invalid word was read out."
         );
     }
 
-    /// Check that whitespace is correctly trimmed.
+    /// Check that every word is validated even if an earlier word is invalid.
     #[test]
-    fn test_whitespace_trim() {
+    fn test_validate_all() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        let decoded: Vec<u8> = validate_all(&coded_words)
+            .into_iter()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(TEST_DATA, &decoded[..TEST_DATA.len()]);
+        assert_eq!(calculate_crc8(&TEST_DATA), decoded[TEST_DATA.len()]);
+
+        assert_eq!(
+            vec![
+                Err(DecodeError::General(
+                    "Invalid word detected in input.".into()
+                )),
+                Ok(0x43),
+                Err(DecodeError::NonAscii { index: 2 }),
+                Err(DecodeError::EmptyWord { index: 3 }),
+            ],
+            validate_all("flax-FLUX-fl\u{e4}x-")
+        );
+        // The CRC is not checked, so an invalid CRC word is still decoded.
+        assert_eq!(
+            vec![Ok(0x43), Ok(0x42), Ok(0x46)],
+            validate_all("flux-flea-full")
+        );
+        assert_eq!(
+            vec![Err(DecodeError::EmptyWord { index: 0 })],
+            validate_all("")
+        );
+    }
+
+    /// Check decoding of tokens split by the caller.
+    #[test]
+    fn test_decode_tokens() {
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            decode_tokens(coded_words.split('-')).unwrap()
+        );
         assert_eq!(
             vec![0x42_u8, 0x43],
-            convert_from_pricklybird(" \t\n\r\x0b\x0c flea-flux-full \t\n\r\x0b\x0c ").unwrap()
+            decode_tokens(vec!["FLEA", "flux", "Full"]).unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            decode_tokens(["flux", "flea", "full"])
+        );
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 1 }),
+            decode_tokens(["flea", "", "full"])
+        );
+        assert_eq!(
+            Err(DecodeError::NonAscii { index: 0 }),
+            decode_tokens([" flea", "flux", "full"])
+        );
+        assert_eq!(
+            Err(DecodeError::General("Input is empty.".into())),
+            decode_tokens([])
         );
     }
 
-    /// Check that edge cases result in the correct errors.
+    /// Check that salted codes only decode with the salt used for encoding.
     #[test]
-    fn test_unusual_input() {
-        let edge_cases = vec![
-            ("", "empty input"),
-            ("orca", "input to short"),
-            ("a®¿a-orca", "invalid characters in input"),
-            ("gäsp-risk-king-orca-husk", "invalid characters in input"),
-            ("-risk-king-orca-husk", "incorrectly formatted input"),
-            ("gasp-rock-king-orca-husk", "incorrect word in input"),
-            ("flea- \t \t-full", "whitespace in input"),
-            ("flea-aaa\0-full", "null bytes in input"),
-            ("flea-\0aaa-full", "null bytes in input"),
-            ("flea-\x7faaa-full", "ASCII control character in input"),
-            ("flea-aaa\x7f-full", "ASCII control character in input"),
-            // Check that no index out of bound error is thrown when the highest
-            // possible value is used to index the hash table.
-            ("zzzz-king", "incorrect word in input"),
-        ];
-        for (edge_case_input, error_reason) in edge_cases {
+    fn test_salted() {
+        let salted_words = convert_to_pricklybird_salted(&TEST_DATA, b"context a");
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_salted(&salted_words, b"context a").unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_salted(&salted_words, b"context b")
+        );
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird(&salted_words)
+        );
+
+        // An empty salt produces the same codes as the unsalted functions.
+        let unsalted_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(
+            unsalted_words,
+            convert_to_pricklybird_salted(&TEST_DATA, &[])
+        );
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_salted(&unsalted_words, &[]).unwrap()
+        );
+        assert_eq!("", convert_to_pricklybird_salted(&[], b"context a"));
+        assert!(convert_from_pricklybird_salted("flea", b"context a").is_err());
+    }
+
+    /// Check that swapped words are located.
+    #[test]
+    fn test_find_transposition() {
+        let coded_words = convert_to_pricklybird(&TEST_DATA[..32]);
+        let word_vec: Vec<&str> = coded_words.split('-').collect();
+        assert_eq!(None, find_transposition(&coded_words));
+
+        for (first, second) in [(0, 1), (10, 11), (31, 32), (3, 20)] {
+            let mut swapped_words = word_vec.clone();
+            swapped_words.swap(first, second);
+            let swapped_code = swapped_words.join("-");
+            let (found_first, found_second) = find_transposition(&swapped_code).unwrap();
+            // Another swap might happen to make the CRC valid as well,
+            // so check that the found swap results in a valid code.
+            swapped_words.swap(found_first, found_second);
             assert!(
-                convert_from_pricklybird(edge_case_input).is_err(),
-                "Converter did not return error for: {} ({})",
-                error_reason,
-                edge_case_input
+                convert_from_pricklybird(&swapped_words.join("-")).is_ok(),
+                "Found swap of {found_first} and {found_second} does not fix the code."
+            );
+        }
+        assert_eq!(Some((1, 2)), find_transposition("flea-full-flux"));
+        assert_eq!(None, find_transposition("flea-flax-full"));
+        assert_eq!(None, find_transposition("flea"));
+    }
+
+    /// Check that the constant time decoder agrees with the standard decoder.
+    #[test]
+    fn test_convert_from_pricklybird_ct() {
+        assert_eq!(calculate_crc8(&TEST_DATA), calculate_crc8_ct(&TEST_DATA));
+        let coded_words = convert_to_pricklybird(TEST_DATA);
+        assert_eq!(
+            TEST_DATA.to_vec(),
+            convert_from_pricklybird_ct(&coded_words).unwrap()
+        );
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            convert_from_pricklybird_ct(" FLEA-Flux-full\n").unwrap()
+        );
+
+        let invalid_word_error = DecodeError::General("Invalid word detected in input.".into());
+        for invalid_input in [
+            "flea-flax-full",
+            "flea-flu-full",
+            "flea-fluxx-full",
+            "flea--full",
+            "flea-fl\u{e4}x-full",
+            "flea-fl[x-full",
+        ] {
+            assert_eq!(
+                Err(invalid_word_error.clone()),
+                convert_from_pricklybird_ct(invalid_input),
+                "Constant time decoder accepted invalid input {invalid_input}."
+            );
+        }
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            convert_from_pricklybird_ct("flux-flea-full")
+        );
+        assert!(convert_from_pricklybird_ct("flea").is_err());
+    }
+
+    /// Check that `WORD_TABLE` decodes every lowercase four letter string
+    /// exactly like the lookup in `HASH_TABLE` followed by the comparison with `BYTE_WORDLIST`.
+    #[test]
+    fn test_word_table_matches_hash_table() {
+        let letters = b'a'..=b'z';
+        for first in letters.clone() {
+            for second in letters.clone() {
+                for third in letters.clone() {
+                    for fourth in letters.clone() {
+                        let word = [first, second, third, fourth];
+                        let table_index = constants::word_hash(first, fourth);
+                        let byte = constants::HASH_TABLE[table_index];
+                        let expected = (word == BYTE_WORDLIST[byte as usize]).then_some(byte);
+                        let (table_word, table_byte) = constants::WORD_TABLE[table_index];
+                        assert_eq!(
+                            expected,
+                            (word == table_word).then_some(table_byte),
+                            "Lookups disagree for '{}'.",
+                            word.escape_ascii()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check that `word_hash_debug` agrees with `word_to_byte` for hits and collisions.
+    #[test]
+    fn test_word_hash_debug() {
+        for (byte, word) in constants::wordlist_entries() {
+            let info = constants::word_hash_debug(&word.to_uppercase()).unwrap();
+            assert!(info.matches, "Word {word} was not found.");
+            assert_eq!(byte, info.table_byte);
+        }
+        let collision = constants::word_hash_debug("fxxx").unwrap();
+        assert_eq!(constants::word_hash(b'f', b'x'), collision.hash_index);
+        assert!(!collision.matches);
+        assert_eq!(None, word_to_byte("fxxx"));
+        for invalid_word in ["", "flu", "fluxx", "fl1x", "fl\u{fc}x"] {
+            assert_eq!(None, constants::word_hash_debug(invalid_word));
+        }
+    }
+
+    /// Check that `wordlist_entries` yields every byte value once in ascending order.
+    #[test]
+    fn test_wordlist_entries() {
+        let entries: Vec<(u8, &str)> = constants::wordlist_entries().collect();
+        assert_eq!(256, entries.len());
+        for (index, &(byte, word)) in entries.iter().enumerate() {
+            assert_eq!(
+                index, byte as usize,
+                "Entries are not in ascending byte order."
+            );
+            assert_eq!(WORDLIST[index], word, "Entry {index} has the wrong word.");
+        }
+    }
+
+    /// Check the boundary between too short input and the shortest valid input.
+    #[test]
+    fn test_word_count_boundary() {
+        let empty_error = DecodeError::General("Input is empty.".into());
+        assert_eq!(Err(empty_error.clone()), convert_from_pricklybird(""));
+        assert_eq!(Err(empty_error.clone()), convert_from_pricklybird(" \n"));
+        assert_eq!(Err(empty_error), convert_from_pricklybird_flexible(" - "));
+
+        let single_word_error = DecodeError::General(
+            "Input consists of a single word, at least one payload word and the CRC word are required."
+                .into(),
+        );
+        // "acid" is the CRC of an empty payload, but a payload is still required.
+        for single_word in ["acid", "flea", "zzzz"] {
+            assert_eq!(
+                Err(single_word_error.clone()),
+                convert_from_pricklybird(single_word),
+                "Converter did not reject single word {single_word}."
+            );
+            assert_eq!(
+                Err(single_word_error.clone()),
+                convert_from_pricklybird_flexible(single_word)
+            );
+            assert_eq!(
+                Err(single_word_error.clone()),
+                convert_from_pricklybird_crc_first(single_word)
+            );
+            assert_eq!(
+                Err(single_word_error.clone()),
+                decode_into_slice(single_word, &mut [0_u8; 4])
             );
         }
+
+        // Two words are a single payload byte and the CRC.
+        assert_eq!(
+            vec![0x42_u8],
+            convert_from_pricklybird("flea-deer").unwrap()
+        );
+        assert_eq!(
+            vec![0x42_u8],
+            convert_from_pricklybird_crc_first("deer-flea").unwrap()
+        );
+        assert!(
+            matches!(
+                convert_from_pricklybird("flea-flea"),
+                Err(DecodeError::CRCError)
+            ),
+            "Converter did not detect invalid CRC in two word input."
+        );
     }
 
     /// Check that empty input results in empty output.
     #[test]
     fn test_empty_input() {
-        assert_eq!("", convert_to_pricklybird(&[]));
+        assert_eq!("", convert_to_pricklybird([]));
         assert!(bytes_to_words(&[]).is_empty());
+        assert!(bytes_to_word_strs(&[]).is_empty());
         assert!(words_to_bytes(&Vec::<&str>::new()).unwrap().is_empty());
     }
 }
@@ -459,6 +5307,98 @@ mod crc8_tests {
         );
     }
 
+    /// Check that the recorded steps match the CRC of every prefix of the data.
+    #[test]
+    fn test_crc_breakdown() {
+        let test_data = b"123456789";
+        let breakdown = crc_breakdown(test_data);
+        assert_eq!(0x37, breakdown.crc);
+        assert_eq!(byte_to_word(0x37), breakdown.word);
+        let prefix_crcs: Vec<u8> = (1..=test_data.len())
+            .map(|len| calculate_crc8(&test_data[..len]))
+            .collect();
+        assert_eq!(prefix_crcs, breakdown.steps);
+
+        let empty_breakdown = crc_breakdown(&[]);
+        assert_eq!(0, empty_breakdown.crc);
+        assert!(
+            empty_breakdown.steps.is_empty(),
+            "Empty input produced steps."
+        );
+    }
+
+    /// Check the CRC with a configurable initial value against known check values.
+    #[test]
+    fn test_crc8_with_init() {
+        assert_eq!(0x37, calculate_crc8_with_init(b"123456789", 0x00));
+        // Check value of CRC-8/HITAG, which uses the same polynomial with an initial value of 0xFF.
+        assert_eq!(0xB4, calculate_crc8_with_init(b"123456789", 0xFF));
+        assert_eq!(0xFF, calculate_crc8_with_init(&[], 0xFF));
+        assert_eq!(
+            CRC8_TABLE[0xFF ^ 0x42],
+            calculate_crc8_with_init(&[0x42], 0xFF),
+            "Initial value is not applied before the first table lookup."
+        );
+    }
+
+    /// Check that the published parameters describe the implemented CRC.
+    #[test]
+    fn test_crc8_params() {
+        let params = constants::crc8_params();
+        assert_eq!(params.check, calculate_crc8(b"123456789"));
+
+        // Generic bitwise CRC calculation driven only by the parameters.
+        let reference_crc = |data: &[u8]| {
+            let mut crc = params.init;
+            for &byte in data {
+                crc ^= if params.reflect_in {
+                    byte.reverse_bits()
+                } else {
+                    byte
+                };
+                for _ in 0..params.width {
+                    crc = if crc & 0x80 == 0 {
+                        crc << 1
+                    } else {
+                        (crc << 1) ^ params.polynomial
+                    };
+                }
+            }
+            let output = if params.reflect_out {
+                crc.reverse_bits()
+            } else {
+                crc
+            };
+            output ^ params.xor_out
+        };
+        let test_data = b"Test data for the CRC parameters.";
+        assert_eq!(reference_crc(test_data), calculate_crc8(test_data));
+        assert_eq!(params.check, reference_crc(b"123456789"));
+
+        let mut data_with_crc = test_data.to_vec();
+        data_with_crc.push(calculate_crc8(test_data));
+        assert_eq!(params.residue, calculate_crc8(&data_with_crc));
+    }
+
+    /// Check that feeding data in chunks gives the same result as the one-shot calculation.
+    #[test]
+    fn test_streaming_chunks() {
+        let test_data = b"Test data for chunked CRC calculation.";
+        let expected = calculate_crc8(test_data);
+        for chunk_size in 1..=test_data.len() {
+            let mut crc = Crc8::new();
+            for chunk in test_data.chunks(chunk_size) {
+                crc.update(chunk);
+            }
+            assert_eq!(
+                expected,
+                crc.finalize(),
+                "Chunked CRC-8 with chunk size {chunk_size} does not match one-shot CRC-8."
+            );
+        }
+        assert_eq!(0, Crc8::new().finalize(), "CRC-8 of no data should be 0.");
+    }
+
     /// Check that data with appended correct CRC-8 has a remainder of zero.
     #[test]
     fn test_with_appended_crc() {
@@ -0,0 +1,757 @@
+//! Configurable encoding and decoding using builders.
+//!
+//! The free functions of the crate cover the common cases.
+//! `EncodeOptions` allows combining separator, case, grouping, CRC layout
+//! and version prefix in one place instead, `DecodeOptions` does the same for decoding.
+//!
+//! # Usage
+//! ```
+//! use pricklybirdlib::{CrcMode, DecodeOptions, EncodeOptions, WordCase};
+//! let code = EncodeOptions::new()
+//!     .separator(' ')
+//!     .case(WordCase::Upper)
+//!     .crc(CrcMode::Crc8)
+//!     .encode(&[0x42, 0x43])
+//!     .unwrap();
+//! assert_eq!("FLEA FLUX FULL", code);
+//! let data = DecodeOptions::new().separator(' ').decode(&code).unwrap();
+//! assert_eq!(vec![0x42, 0x43], data);
+//! ```
+
+use crate::constants::WORDLIST;
+use crate::{
+    DecodeError, EncodeError, EncodeResult, PRICKLYBIRD_VERSION, Result, StreamResult,
+    VERSION_SEPARATOR, calculate_crc8, check_word_count, is_valid_separator, separated_word_count,
+    words_from_utf8, words_to_bytes,
+};
+
+/// Letter case of the words produced by `EncodeOptions`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum WordCase {
+    /// All letters lowercase, as defined by the specification.
+    #[default]
+    Lower,
+    /// All letters uppercase.
+    Upper,
+    /// First letter of each word uppercase.
+    Title,
+}
+
+/// Whether `EncodeOptions` attaches a CRC word.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CrcMode {
+    /// Attach the CRC-8 as a word, as defined by the specification.
+    #[default]
+    Crc8,
+    /// Omit the CRC word, like `bytes_to_pricklybird_string`.
+    None,
+}
+
+/// Position of the CRC word used by `EncodeOptions` and `DecodeOptions`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CrcPosition {
+    /// CRC word in front of the payload, like `convert_to_pricklybird_crc_first`.
+    First,
+    /// CRC word after the payload, as defined by the specification.
+    #[default]
+    Last,
+}
+
+/// Builder for encoding data with non default options.
+///
+/// The defaults produce the same output as `convert_to_pricklybird`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct EncodeOptions {
+    /// Separator placed between words.
+    separator: char,
+    /// Letter case of the words.
+    case: WordCase,
+    /// Number of words per group, zero disables grouping.
+    group: usize,
+    /// Separator placed between groups instead of `separator`.
+    group_separator: char,
+    /// Whether to attach a CRC word.
+    crc: CrcMode,
+    /// Position of the CRC word.
+    crc_position: CrcPosition,
+    /// Whether to prefix the output with `PRICKLYBIRD_VERSION`.
+    versioned: bool,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EncodeOptions {
+    /// Create options producing the same output as `convert_to_pricklybird`.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::EncodeOptions;
+    /// assert_eq!("flea-flux-full", EncodeOptions::new().encode(&[0x42, 0x43]).unwrap());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            separator: '-',
+            case: WordCase::Lower,
+            group: 0,
+            group_separator: ' ',
+            crc: CrcMode::Crc8,
+            crc_position: CrcPosition::Last,
+            versioned: false,
+        }
+    }
+
+    /// Set the separator placed between words, `-` by default.
+    ///
+    /// Encoding fails with `EncodeError::InvalidSeparator`
+    /// if `separator` is rejected by `is_valid_separator`.
+    #[must_use]
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set the letter case of the words, lowercase by default.
+    #[must_use]
+    pub const fn case(mut self, case: WordCase) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Split the words into groups of `words` words, zero disables grouping.
+    ///
+    /// Groups are separated by the group separator, a space by default.
+    /// The CRC word counts towards the groups like any other word.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::EncodeOptions;
+    /// let code = EncodeOptions::new().group(2).encode(&[0x42, 0x43, 0x42]).unwrap();
+    /// assert_eq!("flea-flux flea-kale", code);
+    /// ```
+    #[must_use]
+    pub const fn group(mut self, words: usize) -> Self {
+        self.group = words;
+        self
+    }
+
+    /// Set the separator placed between groups, a space by default.
+    ///
+    /// Encoding fails with `EncodeError::InvalidSeparator`
+    /// if `separator` is rejected by `is_valid_separator`.
+    #[must_use]
+    pub const fn group_separator(mut self, separator: char) -> Self {
+        self.group_separator = separator;
+        self
+    }
+
+    /// Set whether a CRC word is attached, enabled by default.
+    #[must_use]
+    pub const fn crc(mut self, crc: CrcMode) -> Self {
+        self.crc = crc;
+        self
+    }
+
+    /// Set the position of the CRC word, after the payload by default.
+    ///
+    /// Has no effect if the CRC is disabled using `CrcMode::None`.
+    #[must_use]
+    pub const fn crc_position(mut self, crc_position: CrcPosition) -> Self {
+        self.crc_position = crc_position;
+        self
+    }
+
+    /// Prefix the output with `PRICKLYBIRD_VERSION` like `convert_to_pricklybird_versioned`.
+    #[must_use]
+    pub const fn versioned(mut self, versioned: bool) -> Self {
+        self.versioned = versioned;
+        self
+    }
+
+    /// Convert arbitrary data to a pricklybird string using these options.
+    ///
+    /// Empty input results in an empty string, or only the version prefix if enabled.
+    ///
+    /// # Errors
+    /// Will return `EncodeError::InvalidSeparator` if the separator or the group separator
+    /// is rejected by `is_valid_separator`, since the result could not be decoded reliably.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::{CrcPosition, EncodeOptions, WordCase};
+    /// let code = EncodeOptions::new()
+    ///     .case(WordCase::Title)
+    ///     .crc_position(CrcPosition::First)
+    ///     .versioned(true)
+    ///     .encode(&[0x42, 0x43])
+    ///     .unwrap();
+    /// assert_eq!("v1:Full-Flea-Flux", code);
+    /// ```
+    pub fn encode(&self, data: &[u8]) -> EncodeResult<String> {
+        for separator in [self.separator, self.group_separator] {
+            if !is_valid_separator(separator) {
+                return Err(EncodeError::InvalidSeparator { separator });
+            }
+        }
+        Ok(self.encode_words(data))
+    }
+
+    /// Read all bytes from `input` and write them to `output` as a pricklybird string using these options.
+    ///
+    /// Works like `encode_stream`, nothing is written if the options are invalid.
+    /// `output` is flushed after writing.
+    ///
+    /// # Errors
+    /// Will return `StreamError::Encode` with the same errors as `encode`.
+    ///
+    /// Will return `StreamError::Io` with any error returned while reading from `input`
+    /// or writing to `output`.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::EncodeOptions;
+    /// let mut output = Vec::new();
+    /// EncodeOptions::new()
+    ///     .separator('.')
+    ///     .encode_stream(&[0x42_u8, 0x43][..], &mut output)
+    ///     .unwrap();
+    /// assert_eq!(b"flea.flux.full", output.as_slice());
+    /// ```
+    pub fn encode_stream(
+        &self,
+        mut input: impl std::io::Read,
+        mut output: impl std::io::Write,
+    ) -> StreamResult<()> {
+        let mut data = Vec::new();
+        let _ = input.read_to_end(&mut data)?;
+        output.write_all(self.encode(&data)?.as_bytes())?;
+        output.flush()?;
+        Ok(())
+    }
+
+    /// Convert arbitrary data to a pricklybird string like `encode`, without validating the separators.
+    ///
+    /// Used by the functions of the crate that only set separators known to be valid.
+    pub(crate) fn encode_words(&self, data: &[u8]) -> String {
+        let mut words = String::new();
+        if self.versioned {
+            words.push_str(PRICKLYBIRD_VERSION);
+            words.push(VERSION_SEPARATOR);
+        }
+        if data.is_empty() {
+            return words;
+        }
+
+        let crc = calculate_crc8(data);
+        let (before, after): (&[u8], &[u8]) = match (self.crc, self.crc_position) {
+            (CrcMode::Crc8, CrcPosition::First) => (&[crc], &[]),
+            (CrcMode::Crc8, CrcPosition::Last) => (&[], &[crc]),
+            (CrcMode::None, _) => (&[], &[]),
+        };
+        let all_bytes = before.iter().chain(data).chain(after);
+        for (index, &byte) in all_bytes.enumerate() {
+            if index != 0 {
+                if self.group != 0 && index % self.group == 0 {
+                    words.push(self.group_separator);
+                } else {
+                    words.push(self.separator);
+                }
+            }
+            self.push_word(&mut words, WORDLIST[byte as usize]);
+        }
+        words
+    }
+
+    /// Append `word` to `words` in the configured letter case.
+    fn push_word(&self, words: &mut String, word: &str) {
+        match self.case {
+            WordCase::Lower => words.push_str(word),
+            WordCase::Upper => words.push_str(&word.to_ascii_uppercase()),
+            WordCase::Title => {
+                let (first, rest) = word.split_at(1);
+                words.push_str(&first.to_ascii_uppercase());
+                words.push_str(rest);
+            }
+        }
+    }
+}
+
+/// Builder for decoding pricklybird strings with non default options.
+///
+/// The defaults accept the same input as `convert_from_pricklybird`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct DecodeOptions {
+    /// Separator expected between words.
+    separator: char,
+    /// Whether a CRC mismatch is an error.
+    verify_crc: bool,
+    /// Whether surrounding whitespace is ignored.
+    lenient_whitespace: bool,
+    /// Whether input that is not in canonical form is rejected.
+    strict: bool,
+    /// Position of the CRC word.
+    crc_position: CrcPosition,
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecodeOptions {
+    /// Create options accepting the same input as `convert_from_pricklybird`.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::DecodeOptions;
+    /// let data = DecodeOptions::new().decode(" flea-FLUX-full\n").unwrap();
+    /// assert_eq!(vec![0x42, 0x43], data);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            separator: '-',
+            verify_crc: true,
+            lenient_whitespace: true,
+            strict: false,
+            crc_position: CrcPosition::Last,
+        }
+    }
+
+    /// Set the separator expected between words, `-` by default.
+    ///
+    /// Decoding fails with `DecodeError::InvalidSeparator`
+    /// if `separator` is rejected by `is_valid_separator`.
+    #[must_use]
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set whether a CRC mismatch is an error, enabled by default.
+    ///
+    /// When disabled the CRC word is still discarded,
+    /// like `convert_from_pricklybird_opts` with `verify_crc` set to `false`.
+    #[must_use]
+    pub const fn verify_crc(mut self, verify_crc: bool) -> Self {
+        self.verify_crc = verify_crc;
+        self
+    }
+
+    /// Set whether surrounding whitespace is ignored, enabled by default.
+    ///
+    /// Has no effect if `strict` is enabled.
+    #[must_use]
+    pub const fn lenient_whitespace(mut self, lenient_whitespace: bool) -> Self {
+        self.lenient_whitespace = lenient_whitespace;
+        self
+    }
+
+    /// Set whether input that is not in canonical form is rejected, disabled by default.
+    ///
+    /// Canonical input consists of lowercase words separated by the separator
+    /// without surrounding whitespace.
+    /// Strict decoding overrides `lenient_whitespace`, so surrounding whitespace is always rejected.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::DecodeOptions;
+    /// let options = DecodeOptions::new().strict(true);
+    /// assert!(options.decode("flea-flux-full").is_ok());
+    /// assert!(options.decode("FLEA-flux-full").is_err());
+    /// assert!(options.decode("flea-flux-full\n").is_err());
+    /// ```
+    #[must_use]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Set the position of the CRC word, after the payload by default.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::{CrcPosition, DecodeOptions};
+    /// let options = DecodeOptions::new().crc_position(CrcPosition::First);
+    /// assert_eq!(vec![0x42, 0x43], options.decode("full-flea-flux").unwrap());
+    /// ```
+    #[must_use]
+    pub const fn crc_position(mut self, crc_position: CrcPosition) -> Self {
+        self.crc_position = crc_position;
+        self
+    }
+
+    /// Convert a pricklybird string to bytes using these options.
+    ///
+    /// # Errors
+    /// Will return the same errors as `convert_from_pricklybird`,
+    /// except for `DecodeError::CRCError` if `verify_crc` is disabled.
+    ///
+    /// Will return `DecodeError::General` if `strict` is enabled and the input contains uppercase letters.
+    /// Surrounding whitespace is not trimmed if `strict` is enabled or `lenient_whitespace`
+    /// is disabled. It then results in `DecodeError::EmptyWord` if it is the separator,
+    /// for example a leading space with `.separator(' ')`, and in `DecodeError::NonAscii` otherwise.
+    ///
+    /// Will return `DecodeError::InvalidSeparator` if the separator is rejected by `is_valid_separator`.
+    pub fn decode(&self, words: &str) -> Result<Vec<u8>> {
+        if !is_valid_separator(self.separator) {
+            return Err(DecodeError::InvalidSeparator {
+                separator: self.separator,
+            });
+        }
+        let input = if self.lenient_whitespace && !self.strict {
+            words.trim()
+        } else {
+            words
+        };
+        let word_vec: Vec<&str> = input.split(self.separator).collect();
+        check_word_count(separated_word_count(&word_vec))?;
+        let mut payload = words_to_bytes(&word_vec)?;
+        if self.strict && input.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            return Err(DecodeError::General(
+                "Input contains uppercase letters, which strict decoding rejects.".into(),
+            ));
+        }
+
+        let crc_ok = match self.crc_position {
+            CrcPosition::First => {
+                let crc = payload.remove(0);
+                calculate_crc8(&payload) == crc
+            }
+            CrcPosition::Last => {
+                let crc_ok = calculate_crc8(&payload) == 0;
+                // Remove CRC
+                let _ = payload.pop();
+                crc_ok
+            }
+        };
+        if self.verify_crc && !crc_ok {
+            return Err(DecodeError::CRCError);
+        }
+        Ok(payload)
+    }
+
+    /// Read a pricklybird string from `input`, decode it using these options
+    /// and write the decoded bytes to `output`.
+    ///
+    /// Works like `decode_stream`, nothing is written if the input can not be decoded.
+    /// `output` is flushed after writing.
+    ///
+    /// # Errors
+    /// Will return `StreamError::Decode` with the same errors as `decode`,
+    /// or with `DecodeError::General` if the input is not valid UTF-8.
+    ///
+    /// Will return `StreamError::Io` with any error returned while reading from `input`
+    /// or writing to `output`.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::DecodeOptions;
+    /// let mut output = Vec::new();
+    /// DecodeOptions::new()
+    ///     .separator('.')
+    ///     .decode_stream("flea.flux.full\n".as_bytes(), &mut output)
+    ///     .unwrap();
+    /// assert_eq!(vec![0x42, 0x43], output);
+    /// ```
+    pub fn decode_stream(
+        &self,
+        mut input: impl std::io::Read,
+        mut output: impl std::io::Write,
+    ) -> StreamResult<()> {
+        let mut words = Vec::new();
+        let _ = input.read_to_end(&mut words)?;
+        output.write_all(&self.decode(words_from_utf8(&words)?)?)?;
+        output.flush()?;
+        Ok(())
+    }
+}
+
+/// Test encoding and decoding with options.
+#[cfg(test)]
+mod options_tests {
+    use super::*;
+    use crate::{
+        StreamError, bytes_to_pricklybird_string, convert_from_pricklybird,
+        convert_from_pricklybird_any, convert_from_pricklybird_crc_first,
+        convert_from_pricklybird_flexible, convert_to_pricklybird,
+        convert_to_pricklybird_crc_first, convert_to_pricklybird_versioned, encoded_words_len,
+    };
+
+    /// Check that the options reproduce the output of the matching free functions.
+    #[test]
+    fn test_encode_options_match_free_functions() {
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        assert_eq!(
+            convert_to_pricklybird(&data),
+            EncodeOptions::new().encode(&data).unwrap()
+        );
+        assert_eq!(
+            convert_to_pricklybird_crc_first(&data),
+            EncodeOptions::new()
+                .crc_position(CrcPosition::First)
+                .encode(&data)
+                .unwrap()
+        );
+        assert_eq!(
+            bytes_to_pricklybird_string(&data, ' '),
+            EncodeOptions::new()
+                .separator(' ')
+                .crc(CrcMode::None)
+                .encode(&data)
+                .unwrap()
+        );
+        assert_eq!(
+            convert_to_pricklybird_versioned(&data),
+            EncodeOptions::new().versioned(true).encode(&data).unwrap()
+        );
+        assert_eq!("", EncodeOptions::new().encode(&[]).unwrap());
+        assert_eq!(
+            "v1:",
+            EncodeOptions::new().versioned(true).encode(&[]).unwrap()
+        );
+    }
+
+    /// Check that the default decode options match `convert_from_pricklybird`.
+    #[test]
+    fn test_decode_options_default() {
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        let code = convert_to_pricklybird(&data);
+        assert_eq!(data, DecodeOptions::new().decode(&code).unwrap());
+        for input in [
+            "",
+            "flea",
+            "flux-flea-full",
+            "flea-flax-full",
+            " FLEA-flux-full\r\n",
+        ] {
+            assert_eq!(
+                convert_from_pricklybird(input),
+                DecodeOptions::new().decode(input),
+                "Default options differ from convert_from_pricklybird for '{}'.",
+                input.escape_default()
+            );
+        }
+    }
+
+    /// Check each decode option and the interaction of `strict` with `lenient_whitespace`.
+    #[test]
+    fn test_decode_options() {
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            DecodeOptions::new()
+                .separator(' ')
+                .decode("flea flux full")
+                .unwrap()
+        );
+        assert!(
+            DecodeOptions::new()
+                .separator(' ')
+                .decode("flea-flux-full")
+                .is_err()
+        );
+        assert_eq!(
+            vec![0x43_u8, 0x42],
+            DecodeOptions::new()
+                .verify_crc(false)
+                .decode("flux-flea-full")
+                .unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::NonAscii { index: 2 }),
+            DecodeOptions::new()
+                .lenient_whitespace(false)
+                .decode("flea-flux-full\n")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidSeparator { separator: 'x' }),
+            DecodeOptions::new().separator('x').decode("flea-flux-full")
+        );
+
+        let strict = DecodeOptions::new().strict(true).lenient_whitespace(true);
+        assert_eq!(
+            vec![0x42_u8, 0x43],
+            strict.decode("flea-flux-full").unwrap()
+        );
+        assert_eq!(
+            Err(DecodeError::NonAscii { index: 0 }),
+            strict.decode(" flea-flux-full")
+        );
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 0 }),
+            strict.separator(' ').decode(" flea flux full")
+        );
+        assert_eq!(
+            Err(DecodeError::EmptyWord { index: 3 }),
+            DecodeOptions::new()
+                .separator(' ')
+                .lenient_whitespace(false)
+                .decode("flea flux full ")
+        );
+        assert!(matches!(
+            strict.decode("flea-Flux-full"),
+            Err(DecodeError::General(_))
+        ));
+        assert_eq!(Err(DecodeError::CRCError), strict.decode("flux-flea-full"));
+        assert!(strict.verify_crc(false).decode("flux-flea-full").is_ok());
+    }
+
+    /// Check that both CRC positions round trip and detect swapped words.
+    #[test]
+    fn test_crc_position() {
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        for crc_position in [CrcPosition::First, CrcPosition::Last] {
+            let code = EncodeOptions::new()
+                .crc_position(crc_position)
+                .encode(&data)
+                .unwrap();
+            let options = DecodeOptions::new().crc_position(crc_position);
+            assert_eq!(data, options.decode(&code).unwrap());
+
+            let mut words: Vec<&str> = code.split('-').collect();
+            for first in [0, 1, data.len() - 1] {
+                words.swap(first, first + 1);
+                assert_eq!(
+                    Err(DecodeError::CRCError),
+                    options.decode(&words.join("-")),
+                    "Swap of words {first} and {} not detected with CRC position {crc_position:?}.",
+                    first + 1
+                );
+                words.swap(first, first + 1);
+            }
+        }
+
+        assert_eq!(
+            convert_from_pricklybird_crc_first("full-flea-flux"),
+            DecodeOptions::new()
+                .crc_position(CrcPosition::First)
+                .decode("full-flea-flux")
+        );
+        // Decoding with the wrong position fails the CRC check.
+        assert_eq!(
+            Err(DecodeError::CRCError),
+            DecodeOptions::new()
+                .crc_position(CrcPosition::First)
+                .decode("flea-flux-full")
+        );
+        assert_eq!(
+            "flea-flux",
+            EncodeOptions::new()
+                .crc(CrcMode::None)
+                .crc_position(CrcPosition::First)
+                .encode(&[0x42, 0x43])
+                .unwrap()
+        );
+    }
+
+    /// Check that encoding rejects separators and group separators that would prevent decoding.
+    #[test]
+    fn test_encode_options_invalid_separator() {
+        for separator in ['a', 'Z', '\n', ':'] {
+            assert_eq!(
+                Err(EncodeError::InvalidSeparator { separator }),
+                EncodeOptions::new().separator(separator).encode(&[0x42])
+            );
+            assert_eq!(
+                Err(EncodeError::InvalidSeparator { separator }),
+                EncodeOptions::new()
+                    .group(1)
+                    .group_separator(separator)
+                    .encode(&[0x42])
+            );
+        }
+        assert_eq!(
+            DecodeError::InvalidSeparator { separator: 'a' }.to_string(),
+            EncodeError::InvalidSeparator { separator: 'a' }.to_string()
+        );
+    }
+
+    /// Check that the stream functions match `encode` and `decode` and report errors.
+    #[test]
+    fn test_options_streams() {
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        let encode_options = EncodeOptions::new().separator(' ');
+        let mut encoded = Vec::new();
+        encode_options
+            .encode_stream(data.as_slice(), &mut encoded)
+            .unwrap();
+        assert_eq!(encode_options.encode(&data).unwrap().as_bytes(), encoded);
+
+        let mut decoded = Vec::new();
+        DecodeOptions::new()
+            .separator(' ')
+            .decode_stream(encoded.as_slice(), &mut decoded)
+            .unwrap();
+        assert_eq!(data, decoded);
+
+        let mut failed = Vec::new();
+        assert!(
+            matches!(
+                EncodeOptions::new()
+                    .separator('a')
+                    .encode_stream(data.as_slice(), &mut failed),
+                Err(StreamError::Encode(EncodeError::InvalidSeparator {
+                    separator: 'a'
+                }))
+            ),
+            "The invalid separator was not reported as an encode error."
+        );
+        assert!(
+            matches!(
+                DecodeOptions::new().decode_stream(&b"flux-flea-full"[..], &mut failed),
+                Err(StreamError::Decode(DecodeError::CRCError))
+            ),
+            "The invalid CRC was not reported as a decode error."
+        );
+        assert!(failed.is_empty(), "Output was written for invalid input.");
+    }
+
+    /// Check that case and grouping produce codes that still decode.
+    #[test]
+    fn test_encode_options_case_and_group() {
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        let code = EncodeOptions::new()
+            .case(WordCase::Upper)
+            .group(5)
+            .group_separator('/')
+            .encode(&data)
+            .unwrap();
+        let lines: Vec<&str> = code.split('/').collect();
+        assert_eq!(encoded_words_len(data.len()).div_ceil(5), lines.len());
+        assert!(
+            lines[..lines.len() - 1]
+                .iter()
+                .all(|line| line.split('-').count() == 5)
+        );
+        assert_eq!(code, code.to_ascii_uppercase());
+        assert_eq!(data, convert_from_pricklybird_flexible(&code).unwrap());
+
+        let title_code = EncodeOptions::new()
+            .case(WordCase::Title)
+            .versioned(true)
+            .encode(&data)
+            .unwrap();
+        assert!(
+            title_code[3..]
+                .split('-')
+                .all(|word| word.as_bytes()[0].is_ascii_uppercase()
+                    && word.as_bytes()[1..].iter().all(u8::is_ascii_lowercase))
+        );
+        assert_eq!(data, convert_from_pricklybird_any(&title_code).unwrap());
+
+        let crc_first_code = EncodeOptions::new()
+            .crc_position(CrcPosition::First)
+            .case(WordCase::Upper)
+            .encode(&data)
+            .unwrap();
+        assert_eq!(
+            data,
+            convert_from_pricklybird_crc_first(&crc_first_code).unwrap()
+        );
+    }
+}
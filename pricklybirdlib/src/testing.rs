@@ -0,0 +1,214 @@
+//! Helpers for generating pseudorandom data and codes in tests and examples.
+//!
+//! Provides the Lehmer64 generator already used by the test suite and benchmarks,
+//! so no random number generation dependency is required.
+//! The generator is fast and reproducible, but **not** cryptographically secure.
+//! Do not use it to generate codes that must be unpredictable, like recovery codes.
+//!
+//! # Usage
+//! ```
+//! use pricklybirdlib::convert_from_pricklybird;
+//! use pricklybirdlib::testing::{Lehmer64, random_code};
+//! let mut rng = Lehmer64::new(1);
+//! let code = random_code(8, &mut rng);
+//! assert_eq!(8, convert_from_pricklybird(&code).unwrap().len());
+//! ```
+
+use crate::convert_to_pricklybird;
+
+/// Multiplier of the Lehmer64 linear congruential generator.
+const MULTIPLIER: u128 = 0xDA94_2042_E4DD_58B5;
+/// Number of outputs discarded after seeding to compensate for small seeds.
+const WARMUP_ITERATIONS: usize = 128;
+
+/// Pseudorandom number generator using the Lehmer64 LCG.
+///
+/// The same seed always results in the same sequence of values.
+// Copying the generator would silently repeat its output.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Lehmer64 {
+    /// Internal state, always odd.
+    state: u128,
+}
+
+impl Lehmer64 {
+    /// Create a generator from a seed.
+    ///
+    /// The lowest bit of the seed is always set, since an even state
+    /// shortens the period of the generator, so the seeds `0` and `1` are equivalent.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::testing::Lehmer64;
+    /// let mut first = Lehmer64::new(42);
+    /// let mut second = Lehmer64::new(42);
+    /// assert_eq!(first.next_u64(), second.next_u64());
+    /// ```
+    #[must_use]
+    pub const fn new(seed: u128) -> Self {
+        let mut state = seed | 1;
+        let mut i: usize = 0;
+        while i < WARMUP_ITERATIONS {
+            state = state.wrapping_mul(MULTIPLIER);
+            i += 1;
+        }
+        Self { state }
+    }
+
+    /// Advance the generator and return the next pseudorandom value.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(MULTIPLIER);
+        (self.state >> 64) as u64
+    }
+
+    /// Fill `dest` with pseudorandom bytes.
+    ///
+    /// # Usage
+    /// ```
+    /// use pricklybirdlib::testing::Lehmer64;
+    /// let mut buffer = [0_u8; 12];
+    /// Lehmer64::new(1).fill_bytes(&mut buffer);
+    /// assert_ne!([0_u8; 12], buffer);
+    /// ```
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let random_bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&random_bytes[..chunk.len()]);
+        }
+    }
+}
+
+/// Generate `len` deterministic pseudorandom bytes from `seed`.
+///
+/// Intended for reproducible tests and benchmarks only, the output is predictable
+/// and must not be used for cryptographic purposes.
+/// Returns the same bytes as `deterministic_array` for the same seed.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::testing::deterministic_bytes;
+/// let data = deterministic_bytes(1, 100);
+/// assert_eq!(100, data.len());
+/// assert_eq!(data, deterministic_bytes(1, 100));
+/// ```
+#[must_use]
+pub fn deterministic_bytes(seed: u128, len: usize) -> Vec<u8> {
+    let mut data = vec![0_u8; len];
+    Lehmer64::new(seed).fill_bytes(&mut data);
+    data
+}
+
+/// Generate `N` deterministic pseudorandom bytes from `seed` at compile time.
+///
+/// Intended for reproducible tests and benchmarks only, the output is predictable
+/// and must not be used for cryptographic purposes.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::testing::{deterministic_array, deterministic_bytes};
+/// const DATA: [u8; 64] = deterministic_array(1);
+/// assert_eq!(deterministic_bytes(1, 64), DATA);
+/// ```
+#[must_use]
+pub const fn deterministic_array<const N: usize>(seed: u128) -> [u8; N] {
+    let mut rng = Lehmer64::new(seed);
+    let mut result = [0_u8; N];
+    let mut i: usize = 0;
+    while i < N {
+        let random_bytes = rng.next_u64().to_le_bytes();
+        let mut j: usize = 0;
+        while j < random_bytes.len() && i + j < N {
+            result[i + j] = random_bytes[j];
+            j += 1;
+        }
+        i += random_bytes.len();
+    }
+    result
+}
+
+/// Generate a code for a pseudorandom `len` byte payload, including the CRC.
+///
+/// Handy for examples and fuzz style tests.
+/// An empty payload results in an empty string, like in `convert_to_pricklybird`.
+///
+/// # Usage
+/// ```
+/// use pricklybirdlib::testing::{Lehmer64, random_code};
+/// let code = random_code(4, &mut Lehmer64::new(7));
+/// assert_eq!(5, code.split('-').count());
+/// ```
+#[must_use]
+pub fn random_code(len: usize, rng: &mut Lehmer64) -> String {
+    let mut data = vec![0_u8; len];
+    rng.fill_bytes(&mut data);
+    convert_to_pricklybird(&data)
+}
+
+/// Test the pseudorandom generation helpers.
+#[cfg(test)]
+mod testing_tests {
+    use super::*;
+    use crate::convert_from_pricklybird;
+
+    /// Check that generation is reproducible and depends on the seed.
+    #[test]
+    fn test_lehmer64_reproducible() {
+        let mut first = Lehmer64::new(1);
+        let mut second = Lehmer64::new(1);
+        let mut other = Lehmer64::new(2);
+        let first_values: Vec<u64> = (0..16).map(|_| first.next_u64()).collect();
+        let second_values: Vec<u64> = (0..16).map(|_| second.next_u64()).collect();
+        let other_values: Vec<u64> = (0..16).map(|_| other.next_u64()).collect();
+        assert_eq!(first_values, second_values);
+        assert_ne!(first_values, other_values, "Seed does not affect output.");
+        assert_eq!(
+            Lehmer64::new(0),
+            Lehmer64::new(1),
+            "Even seeds must be made odd."
+        );
+    }
+
+    /// Check that `fill_bytes` handles lengths that are not a multiple of eight.
+    #[test]
+    fn test_fill_bytes() {
+        let mut rng = Lehmer64::new(3);
+        let expected: Vec<u8> = [rng.next_u64(), rng.next_u64()]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .take(13)
+            .collect();
+        let mut buffer = [0_u8; 13];
+        Lehmer64::new(3).fill_bytes(&mut buffer);
+        assert_eq!(expected, buffer);
+    }
+
+    /// Check that the fixed size and variable length generators agree.
+    #[test]
+    fn test_deterministic_bytes() {
+        const DATA: [u8; 29] = deterministic_array(9);
+        assert_eq!(deterministic_bytes(9, 29), DATA);
+        assert_eq!(deterministic_bytes(9, 29)[..10], deterministic_bytes(9, 10));
+        assert_ne!(deterministic_bytes(9, 29), deterministic_bytes(10, 29));
+        assert!(
+            deterministic_bytes(9, 0).is_empty(),
+            "Empty length produced data."
+        );
+    }
+
+    /// Check that random codes decode to payloads of the requested length.
+    #[test]
+    fn test_random_code() {
+        let mut rng = Lehmer64::new(5);
+        for len in [1, 2, 7, 8, 9, 100] {
+            let code = random_code(len, &mut rng);
+            assert_eq!(
+                len,
+                convert_from_pricklybird(&code).unwrap().len(),
+                "Random code has the wrong length."
+            );
+        }
+        assert_eq!("", random_code(0, &mut rng));
+    }
+}
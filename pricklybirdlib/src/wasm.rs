@@ -0,0 +1,43 @@
+//! Bindings for use from JavaScript, enabled by the `wasm` feature.
+//!
+//! Exports `encode` and `decode` using `wasm-bindgen`.
+//! Decoding errors are thrown as JavaScript `Error` objects
+//! containing the `Display` message of the `DecodeError`.
+//!
+//! # Building
+//! Build a crate that depends on `pricklybirdlib` with the `wasm` feature enabled
+//! and `crate-type = ["cdylib"]` using `wasm-pack`, the exports are included automatically.
+//! ```console
+//! $ wasm-pack build --target web
+//! ```
+//! ```js
+//! import init, { encode, decode } from "./pkg/your_crate.js";
+//! await init();
+//! const code = encode(new Uint8Array([0x42, 0x43])); // "flea-flux-full"
+//! const data = decode(code); // Uint8Array [0x42, 0x43]
+//! ```
+
+use wasm_bindgen::prelude::{JsError, JsValue, wasm_bindgen};
+
+use crate::{convert_from_pricklybird, convert_to_pricklybird};
+
+/// Convert arbitrary data to a pricklybird string and attach CRC.
+///
+/// Wraps `convert_to_pricklybird`.
+#[wasm_bindgen]
+#[must_use]
+pub fn encode(data: &[u8]) -> String {
+    convert_to_pricklybird(data)
+}
+
+/// Convert a pricklybird string to bytes and check CRC.
+///
+/// Wraps `convert_from_pricklybird`.
+///
+/// # Errors
+/// Throws a JavaScript `Error` with the message of the `DecodeError`
+/// returned by `convert_from_pricklybird`.
+#[wasm_bindgen]
+pub fn decode(s: &str) -> Result<Vec<u8>, JsValue> {
+    convert_from_pricklybird(s).map_err(|err| JsError::new(&err.to_string()).into())
+}
@@ -0,0 +1,163 @@
+//! Inputs that previously misbehaved or are likely to, each with the correct behavior asserted.
+//!
+//! This is the permanent home for inputs found by the fuzz target in the `fuzz` directory.
+//! To add a finding, append a `Regression` to `regressions` with a name describing
+//! the input and the result `convert_from_pricklybird` must return for it.
+//! Every input is also passed to the other decoders to check that none of them panic.
+
+use pricklybirdlib::{
+    DecodeError, DecodeOptions, convert_from_pricklybird, convert_from_pricklybird_checked,
+    convert_from_pricklybird_correcting, convert_from_pricklybird_ct,
+    convert_from_pricklybird_flexible, convert_from_pricklybird_optional_crc,
+    convert_from_pricklybird_opts, convert_from_pricklybird_tolerant, convert_to_pricklybird,
+    crc_of_code, decode_stream, decode_verbose, decoded_len, diff_codes, find_transposition,
+    first_error_position, is_complete, normalize, word_count,
+};
+
+/// An input that previously misbehaved and the result it must decode to.
+struct Regression {
+    /// Short description of the input, reported if the case fails.
+    name: &'static str,
+    /// Input passed to the decoders.
+    input: &'static str,
+    /// Result `convert_from_pricklybird` must return.
+    expected: Result<Vec<u8>, DecodeError>,
+}
+
+/// Create a case expecting `DecodeError::General` with `message`.
+fn general(name: &'static str, input: &'static str, message: &str) -> Regression {
+    Regression {
+        name,
+        input,
+        expected: Err(DecodeError::General(message.to_owned())),
+    }
+}
+
+/// Create a case expecting `DecodeError::NonAscii` for the word at `index`.
+const fn non_ascii(name: &'static str, input: &'static str, index: usize) -> Regression {
+    Regression {
+        name,
+        input,
+        expected: Err(DecodeError::NonAscii { index }),
+    }
+}
+
+/// Create a case expecting `DecodeError::EmptyWord` for the word at `index`.
+const fn empty_word(name: &'static str, input: &'static str, index: usize) -> Regression {
+    Regression {
+        name,
+        input,
+        expected: Err(DecodeError::EmptyWord { index }),
+    }
+}
+
+/// Create a case expecting the input to decode to `payload`.
+fn decodes(name: &'static str, input: &'static str, payload: &[u8]) -> Regression {
+    Regression {
+        name,
+        input,
+        expected: Ok(payload.to_vec()),
+    }
+}
+
+/// All known regression cases.
+fn regressions() -> Vec<Regression> {
+    vec![
+        general("empty input", "", "Input is empty."),
+        general(
+            "single word",
+            "orca",
+            "Input consists of a single word, at least one payload word and the CRC word are required.",
+        ),
+        non_ascii("non ASCII word", "a\u{ae}\u{bf}a-orca", 0),
+        non_ascii("umlaut in word", "g\u{e4}sp-risk-king-orca-husk", 0),
+        empty_word("leading separator", "-risk-king-orca-husk", 0),
+        empty_word("repeated separator", "flea--full", 1),
+        empty_word("trailing separator", "flea-flux-full-", 3),
+        general(
+            "word not in wordlist",
+            "gasp-rock-king-orca-husk",
+            "Invalid word detected in input.",
+        ),
+        non_ascii("whitespace word", "flea- \t \t-full", 1),
+        non_ascii("trailing null byte", "flea-aaa\0-full", 1),
+        non_ascii("leading null byte", "flea-\0aaa-full", 1),
+        non_ascii("leading DEL", "flea-\x7faaa-full", 1),
+        non_ascii("trailing DEL", "flea-aaa\x7f-full", 1),
+        non_ascii("only DEL", "flea-\x7f\x7f\x7f\x7f-full", 1),
+        non_ascii("two byte character", "flea-fl\u{80}-full", 1),
+        non_ascii("two byte character at start", "flea-\u{7ff}lu-full", 1),
+        // The highest possible value used to index the hash table.
+        general(
+            "highest hash table index",
+            "zzzz-king",
+            "Invalid word detected in input.",
+        ),
+        general(
+            "lowest hash table index",
+            "aaaa-king",
+            "Invalid word detected in input.",
+        ),
+        decodes("uppercase words", "FLEA-FLUX-FULL", &[0x42, 0x43]),
+        decodes("surrounding whitespace", " flea-flux-full\n", &[0x42, 0x43]),
+    ]
+}
+
+/// Test the regression cases.
+#[cfg(test)]
+mod regression_tests {
+    use super::*;
+
+    /// Check that every case decodes to its expected result.
+    #[test]
+    fn test_regressions_decode() {
+        for case in regressions() {
+            assert_eq!(
+                case.expected,
+                convert_from_pricklybird(case.input),
+                "Regression '{}' failed for input '{}'.",
+                case.name,
+                case.input.escape_default()
+            );
+        }
+    }
+
+    /// Check that no decoder panics on any case and that decoded data round trips.
+    #[test]
+    fn test_regressions_no_panic() {
+        for case in regressions() {
+            let input = case.input;
+            for decoded in [
+                convert_from_pricklybird_flexible(input),
+                convert_from_pricklybird_opts(input, false),
+                convert_from_pricklybird_ct(input),
+                DecodeOptions::new().decode(input),
+                convert_from_pricklybird_checked(input).map(|result| result.payload),
+                convert_from_pricklybird_tolerant(input).map(|result| result.payload),
+                convert_from_pricklybird_optional_crc(input).map(|(payload, _)| payload),
+                convert_from_pricklybird_correcting(input).map(|(payload, _)| payload),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                assert_eq!(
+                    Ok(decoded.clone()),
+                    convert_from_pricklybird(&convert_to_pricklybird(&decoded)),
+                    "Decoded data of regression '{}' did not round trip.",
+                    case.name
+                );
+            }
+
+            let _ = decode_verbose(input);
+            let _ = crc_of_code(input);
+            let _ = normalize(input);
+            let _ = decode_stream(input.as_bytes(), Vec::new());
+            let _ = diff_codes(input, "flea-flux-full");
+            let _ = find_transposition(input);
+            let _ = first_error_position(input);
+            let _ = is_complete(input);
+            let _ = word_count(input);
+            let _ = decoded_len(input);
+        }
+    }
+}